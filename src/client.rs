@@ -2,14 +2,65 @@ use serenity::model::gateway::GatewayIntents;
 use songbird::SerenityInit;
 
 use std::{collections::HashMap, env, error::Error};
+use tokio::signal;
 
 use crate::{
+    errors::ParrotError,
     guild::{cache::GuildCacheMap, settings::GuildSettingsMap},
     handlers::SerenityHandler,
+    sources::{
+        cache::{load_metadata_cache, save_metadata_cache},
+        spotify::{Spotify, SPOTIFY},
+    },
 };
 
 pub struct Client {
     client: serenity::Client,
+    shards: ShardConfig,
+}
+
+/// How the gateway connection is split up into shards, configured through the `SHARDS`
+/// (`auto` or a total count) and `SHARD_RANGE` (e.g. `0-3`) environment variables.
+#[derive(Debug, PartialEq)]
+pub enum ShardConfig {
+    Single,
+    Auto,
+    Total(u32),
+    Range { first: u32, last: u32, total: u32 },
+}
+
+impl ShardConfig {
+    pub fn from_env() -> Result<ShardConfig, ParrotError> {
+        let shards = env::var("SHARDS").ok();
+        let range = env::var("SHARD_RANGE").ok();
+        ShardConfig::parse(shards.as_deref(), range.as_deref())
+    }
+
+    pub fn parse(shards: Option<&str>, range: Option<&str>) -> Result<ShardConfig, ParrotError> {
+        let total =
+            match shards.map(str::trim) {
+                None | Some("") => return Ok(ShardConfig::Single),
+                Some("auto") if range.is_none() => return Ok(ShardConfig::Auto),
+                Some(total) => total.parse::<u32>().ok().filter(|total| *total > 0).ok_or(
+                    ParrotError::Dynamic(format!("Invalid shard count: {}", total)),
+                )?,
+            };
+
+        let Some(range) = range.map(str::trim).filter(|range| !range.is_empty()) else {
+            return Ok(ShardConfig::Total(total));
+        };
+
+        let (first, last) = range
+            .split_once('-')
+            .and_then(|(first, last)| Some((first.parse().ok()?, last.parse().ok()?)))
+            .filter(|(first, last): &(u32, u32)| first <= last && *last < total)
+            .ok_or(ParrotError::Dynamic(format!(
+                "Invalid shard range: {}",
+                range
+            )))?;
+
+        Ok(ShardConfig::Range { first, last, total })
+    }
 }
 
 impl Client {
@@ -19,6 +70,7 @@ impl Client {
     }
 
     pub async fn new(token: String) -> Result<Client, Box<dyn Error>> {
+        let shards = ShardConfig::from_env()?;
         let gateway_intents = GatewayIntents::non_privileged();
 
         let client = serenity::Client::builder(token, gateway_intents)
@@ -31,10 +83,54 @@ impl Client {
         data.insert::<GuildSettingsMap>(HashMap::default());
         drop(data);
 
-        Ok(Client { client })
+        Ok(Client { client, shards })
     }
 
+    /// Runs the bot until it crashes or is asked to stop, saving the metadata cache on the way out.
     pub async fn start(&mut self) -> Result<(), serenity::Error> {
-        self.client.start().await
+        load_metadata_cache().await;
+
+        // attempts to authenticate to spotify, once for every shard this process runs
+        *SPOTIFY.lock().await = Spotify::auth().await;
+
+        let shard_manager = self.client.shard_manager.clone();
+        let result = tokio::select! {
+            result = self.run() => result,
+            _ = shutdown_signal() => {
+                shard_manager.shutdown_all().await;
+                Ok(())
+            }
+        };
+
+        save_metadata_cache(true).await;
+        result
+    }
+
+    async fn run(&mut self) -> Result<(), serenity::Error> {
+        match self.shards {
+            ShardConfig::Single => self.client.start().await,
+            ShardConfig::Auto => self.client.start_autosharded().await,
+            ShardConfig::Total(total) => self.client.start_shards(total).await,
+            // serenity treats the end of the range as inclusive
+            ShardConfig::Range { first, last, total } => {
+                self.client.start_shard_range(first..last, total).await
+            }
+        }
     }
 }
+
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        let mut terminate = signal::unix::signal(signal::unix::SignalKind::terminate())
+            .expect("Fatality! Could not listen for SIGTERM!");
+
+        tokio::select! {
+            _ = signal::ctrl_c() => {}
+            _ = terminate.recv() => {}
+        }
+    }
+
+    #[cfg(not(unix))]
+    let _ = signal::ctrl_c().await;
+}
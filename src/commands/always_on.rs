@@ -0,0 +1,78 @@
+use crate::{
+    errors::ParrotError,
+    guild::settings::{GuildSettings, GuildSettingsMap},
+    handlers::always_on::{ensure_always_on, stop_rejoin},
+    messaging::message::ParrotMessage,
+    messaging::messages::{FAIL_GUILD_ONLY, FAIL_MISSING_CHANNEL, FAIL_MISSING_SUBCOMMAND},
+    utils::create_response,
+};
+use serenity::{
+    all::{CommandDataOptionValue, CommandInteraction},
+    client::Context,
+    prelude::Mentionable,
+};
+
+pub async fn always_on(
+    ctx: &Context,
+    interaction: &mut CommandInteraction,
+) -> Result<(), ParrotError> {
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
+
+    let subcommand = interaction
+        .data
+        .options
+        .first()
+        .ok_or(ParrotError::Other(FAIL_MISSING_SUBCOMMAND))?;
+
+    let channel = match &subcommand.value {
+        CommandDataOptionValue::SubCommand(sub_options) if subcommand.name == "enable" => {
+            let channel_id = sub_options
+                .iter()
+                .find(|opt| opt.name == "channel")
+                .and_then(|opt| opt.value.as_channel_id())
+                .ok_or(ParrotError::Other(FAIL_MISSING_CHANNEL))?;
+
+            let playlist = sub_options
+                .iter()
+                .find(|opt| opt.name == "playlist")
+                .and_then(|opt| opt.value.as_str())
+                .map(|playlist| playlist.to_string());
+
+            Some((channel_id, playlist))
+        }
+        _ => None,
+    };
+
+    let mut data = ctx.data.write().await;
+    let settings = data.get_mut::<GuildSettingsMap>().unwrap();
+
+    let guild_settings = settings
+        .entry(guild_id)
+        .or_insert_with(|| GuildSettings::new(guild_id));
+
+    match channel.clone() {
+        Some((channel_id, playlist)) => guild_settings.enable_always_on(channel_id, playlist),
+        None => guild_settings.disable_always_on(),
+    }
+    guild_settings.save()?;
+    drop(data);
+
+    let Some((channel_id, playlist)) = channel else {
+        stop_rejoin(&ctx.data, guild_id).await;
+        return create_response(&ctx.http, interaction, ParrotMessage::AlwaysOnDisabled).await;
+    };
+
+    ensure_always_on(ctx, guild_id).await;
+
+    create_response(
+        &ctx.http,
+        interaction,
+        ParrotMessage::AlwaysOnEnabled {
+            channel: channel_id.mention(),
+            playlist,
+        },
+    )
+    .await
+}
@@ -0,0 +1,46 @@
+use crate::{
+    errors::ParrotError,
+    guild::settings::{GuildSettings, GuildSettingsMap},
+    handlers::voice_state::handle_listener_change,
+    messaging::message::ParrotMessage,
+    messaging::messages::FAIL_GUILD_ONLY,
+    utils::create_response,
+};
+use serenity::{all::CommandInteraction, client::Context};
+
+pub async fn autoleave(
+    ctx: &Context,
+    interaction: &mut CommandInteraction,
+) -> Result<(), ParrotError> {
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
+
+    let grace = interaction
+        .data
+        .options
+        .first()
+        .and_then(|opt| opt.value.as_i64())
+        .map(|grace| grace.max(0) as u64);
+
+    let mut data = ctx.data.write().await;
+    let settings = data.get_mut::<GuildSettingsMap>().unwrap();
+
+    let guild_settings = settings
+        .entry(guild_id)
+        .or_insert_with(|| GuildSettings::new(guild_id));
+    guild_settings.set_autoleave(grace);
+    guild_settings.save()?;
+
+    let (enabled, grace) = guild_settings.autoleave_settings();
+    drop(data);
+
+    // the channel might already be empty, or a pending departure is no longer wanted
+    handle_listener_change(ctx, guild_id).await;
+
+    if enabled {
+        create_response(&ctx.http, interaction, ParrotMessage::AutoleaveOn { grace }).await
+    } else {
+        create_response(&ctx.http, interaction, ParrotMessage::AutoleaveOff).await
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{
+    errors::ParrotError,
+    guild::settings::{GuildSettings, GuildSettingsMap},
+    handlers::now_playing::default_activity,
+    messaging::message::ParrotMessage,
+    messaging::messages::FAIL_GUILD_ONLY,
+    utils::create_response,
+};
+use serenity::{all::CommandInteraction, client::Context};
+
+pub async fn broadcast(
+    ctx: &Context,
+    interaction: &mut CommandInteraction,
+) -> Result<(), ParrotError> {
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
+
+    let get_option = |name: &str| {
+        interaction
+            .data
+            .options
+            .iter()
+            .find(|opt| opt.name == name)
+            .and_then(|opt| opt.value.as_bool())
+    };
+
+    let mut data = ctx.data.write().await;
+    let settings = data.get_mut::<GuildSettingsMap>().unwrap();
+
+    let guild_settings = settings
+        .entry(guild_id)
+        .or_insert_with(|| GuildSettings::new(guild_id));
+
+    let had_nickname = guild_settings.broadcast_nickname;
+    let had_presence = guild_settings.broadcast_presence;
+
+    if let Some(status) = get_option("status") {
+        guild_settings.broadcast_status = status;
+    }
+    if let Some(nickname) = get_option("nickname") {
+        guild_settings.broadcast_nickname = nickname;
+    }
+    if let Some(presence) = get_option("presence") {
+        guild_settings.broadcast_presence = presence;
+    }
+    guild_settings.save()?;
+
+    let message = ParrotMessage::Broadcast {
+        status: guild_settings.broadcast_status,
+        nickname: guild_settings.broadcast_nickname,
+        presence: guild_settings.broadcast_presence,
+    };
+    let reset_nickname = had_nickname && !guild_settings.broadcast_nickname;
+    let reset_presence = had_presence && !guild_settings.broadcast_presence;
+    drop(data);
+
+    // the last track shown would otherwise stick around until the bot restarts
+    if reset_nickname {
+        if let Err(e) = guild_id.edit_nickname(&ctx.http, None).await {
+            eprintln!("[WARN] Failed to reset nickname: {}", e);
+        }
+    }
+    if reset_presence && ctx.cache.guild_count() == 1 {
+        ctx.set_activity(Some(default_activity()));
+    }
+
+    create_response(&ctx.http, interaction, message).await
+}
@@ -0,0 +1,10 @@
+use crate::{
+    errors::ParrotError, messaging::message::ParrotMessage, sources::cache::METADATA_CACHE,
+    utils::create_response,
+};
+use serenity::{all::CommandInteraction, client::Context};
+
+pub async fn cache(ctx: &Context, interaction: &mut CommandInteraction) -> Result<(), ParrotError> {
+    let stats = METADATA_CACHE.lock().await.stats();
+    create_response(&ctx.http, interaction, ParrotMessage::CacheStats { stats }).await
+}
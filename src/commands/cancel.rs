@@ -0,0 +1,79 @@
+use crate::{
+    errors::{verify, ParrotError},
+    guild::cache::GuildCacheMap,
+    messaging::message::ParrotMessage,
+    messaging::messages::{FAIL_GUILD_ONLY, FAIL_NO_IMPORTS},
+    utils::create_response,
+};
+use serenity::{
+    all::CommandInteraction,
+    client::Context,
+    model::id::{GuildId, InteractionId},
+    prelude::{RwLock, TypeMap},
+};
+use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
+
+pub async fn cancel(
+    ctx: &Context,
+    interaction: &mut CommandInteraction,
+) -> Result<(), ParrotError> {
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
+
+    let cancelled = cancel_imports(&ctx.data, guild_id).await;
+    verify(cancelled > 0, ParrotError::Other(FAIL_NO_IMPORTS))?;
+
+    create_response(&ctx.http, interaction, ParrotMessage::ImportCancelled).await
+}
+
+/// Tracks a playlist import so that it can later be aborted with [`cancel_imports`].
+pub async fn register_import(
+    data: &Arc<RwLock<TypeMap>>,
+    guild_id: GuildId,
+    id: InteractionId,
+) -> CancellationToken {
+    let token = CancellationToken::new();
+
+    let mut data = data.write().await;
+    if let Some(cache_map) = data.get_mut::<GuildCacheMap>() {
+        let cache = cache_map.entry(guild_id).or_default();
+        cache.pending_imports.insert(id, token.clone());
+    }
+
+    token
+}
+
+pub async fn forget_import(
+    data: &Arc<RwLock<TypeMap>>,
+    guild_id: GuildId,
+    id: InteractionId,
+) -> Result<(), ()> {
+    let mut data = data.write().await;
+
+    let cache_map = data.get_mut::<GuildCacheMap>().ok_or(())?;
+    let cache = cache_map.get_mut(&guild_id).ok_or(())?;
+    cache.pending_imports.remove(&id);
+
+    Ok(())
+}
+
+/// Aborts every playlist import still in progress for a guild, returning how many there were.
+pub async fn cancel_imports(data: &Arc<RwLock<TypeMap>>, guild_id: GuildId) -> usize {
+    let mut data = data.write().await;
+
+    let Some(cache) = data
+        .get_mut::<GuildCacheMap>()
+        .and_then(|cache_map| cache_map.get_mut(&guild_id))
+    else {
+        return 0;
+    };
+
+    let imports = std::mem::take(&mut cache.pending_imports);
+    for token in imports.values() {
+        token.cancel();
+    }
+
+    imports.len()
+}
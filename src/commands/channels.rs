@@ -0,0 +1,64 @@
+use crate::{
+    errors::ParrotError,
+    guild::settings::{GuildSettings, GuildSettingsMap},
+    messaging::message::ParrotMessage,
+    messaging::messages::{FAIL_GUILD_ONLY, FAIL_MISSING_CHANNEL, FAIL_MISSING_SUBCOMMAND},
+    utils::{create_response, mention_all},
+};
+use serenity::{
+    all::{CommandDataOptionValue, CommandInteraction},
+    client::Context,
+};
+
+pub async fn channels(
+    ctx: &Context,
+    interaction: &mut CommandInteraction,
+) -> Result<(), ParrotError> {
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
+
+    let subcommand = interaction
+        .data
+        .options
+        .first()
+        .ok_or(ParrotError::Other(FAIL_MISSING_SUBCOMMAND))?;
+
+    let channel_id = match &subcommand.value {
+        CommandDataOptionValue::SubCommand(sub_options) => sub_options
+            .iter()
+            .find(|opt| opt.name == "channel")
+            .and_then(|opt| opt.value.as_channel_id()),
+        _ => None,
+    };
+
+    let mut data = ctx.data.write().await;
+    let settings = data.get_mut::<GuildSettingsMap>().unwrap();
+
+    let guild_settings = settings
+        .entry(guild_id)
+        .or_insert_with(|| GuildSettings::new(guild_id));
+
+    match (subcommand.name.as_str(), channel_id) {
+        ("text", Some(channel_id)) => {
+            guild_settings.toggle_text_channel(channel_id);
+        }
+        ("voice", Some(channel_id)) => {
+            guild_settings.toggle_voice_channel(channel_id);
+        }
+        ("reset", _) => {
+            guild_settings.allowed_text_channels.clear();
+            guild_settings.allowed_voice_channels.clear();
+        }
+        _ => return Err(ParrotError::Other(FAIL_MISSING_CHANNEL)),
+    }
+    guild_settings.save()?;
+
+    let message = ParrotMessage::Channels {
+        text: mention_all(&guild_settings.allowed_text_channels),
+        voice: mention_all(&guild_settings.allowed_voice_channels),
+    };
+    drop(data);
+
+    create_response(&ctx.http, interaction, message).await
+}
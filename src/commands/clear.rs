@@ -1,20 +1,25 @@
 use crate::{
+    commands::cancel::cancel_imports,
     errors::{verify, ParrotError},
     handlers::track_end::update_queue_messages,
     messaging::message::ParrotMessage,
+    messaging::messages::{FAIL_GUILD_ONLY, FAIL_VOICE_MANAGER},
     utils::create_response,
 };
 use serenity::{all::CommandInteraction, client::Context};
 
 pub async fn clear(ctx: &Context, interaction: &mut CommandInteraction) -> Result<(), ParrotError> {
-    let guild_id = interaction.guild_id.ok_or(ParrotError::Other(
-        "This command can only be used in a server",
-    ))?;
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
     let manager = songbird::get(ctx)
         .await
-        .ok_or(ParrotError::Other("Voice manager not configured"))?;
+        .ok_or(ParrotError::Other(FAIL_VOICE_MANAGER))?;
     let call = manager.get(guild_id).ok_or(ParrotError::NotConnected)?;
 
+    // pending imports would otherwise keep refilling the queue
+    cancel_imports(&ctx.data, guild_id).await;
+
     let handler = call.lock().await;
     let queue = handler.queue().current_queue();
 
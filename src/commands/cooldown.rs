@@ -0,0 +1,103 @@
+use crate::{
+    errors::{verify, ParrotError},
+    guild::settings::{GuildSettings, GuildSettingsMap},
+    messaging::{
+        i18n::tr,
+        message::ParrotMessage,
+        messages::{FAIL_GUILD_ONLY, FAIL_MISSING_SUBCOMMAND, FAIL_UNKNOWN_COMMAND},
+    },
+    utils::create_response,
+};
+use serenity::{
+    all::{CommandDataOptionValue, CommandInteraction},
+    client::Context,
+    model::application::Command,
+};
+
+const DEFAULT_QUEUE_WINDOW: u64 = 60;
+
+pub async fn cooldown(
+    ctx: &Context,
+    interaction: &mut CommandInteraction,
+) -> Result<(), ParrotError> {
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
+
+    let subcommand = interaction
+        .data
+        .options
+        .first()
+        .ok_or(ParrotError::Other(FAIL_MISSING_SUBCOMMAND))?;
+
+    let sub_options = match &subcommand.value {
+        CommandDataOptionValue::SubCommand(sub_options) => sub_options.as_slice(),
+        _ => &[],
+    };
+
+    let get_option = |name: &str| {
+        sub_options
+            .iter()
+            .find(|opt| opt.name == name)
+            .and_then(|opt| opt.value.as_i64())
+            .map(|value| value.max(0) as u64)
+    };
+
+    let command_name = sub_options
+        .iter()
+        .find(|opt| opt.name == "name")
+        .and_then(|opt| opt.value.as_str())
+        .map(|name| name.trim().trim_start_matches('/').to_lowercase());
+
+    if let Some(command_name) = &command_name {
+        let exists = Command::get_global_commands(&ctx.http)
+            .await?
+            .iter()
+            .any(|command| &command.name == command_name);
+        verify(
+            exists,
+            ParrotError::Dynamic(format!("{} `/{}`", tr(FAIL_UNKNOWN_COMMAND), command_name)),
+        )?;
+    }
+
+    let mut data = ctx.data.write().await;
+    let settings = data.get_mut::<GuildSettingsMap>().unwrap();
+
+    let guild_settings = settings
+        .entry(guild_id)
+        .or_insert_with(|| GuildSettings::new(guild_id));
+
+    match (subcommand.name.as_str(), &command_name) {
+        ("command", Some(command_name)) => {
+            let seconds = get_option("seconds").unwrap_or(0);
+            guild_settings.set_command_cooldown(command_name, seconds);
+        }
+        ("queue", _) => {
+            let commands = get_option("commands").unwrap_or(0) as u32;
+            let window = get_option("seconds").unwrap_or(DEFAULT_QUEUE_WINDOW);
+            guild_settings.set_queue_rate_limit(commands, window);
+        }
+        _ => {}
+    }
+
+    let mut cooldowns: Vec<(String, u64)> = guild_settings
+        .command_cooldowns
+        .iter()
+        .map(|(command, seconds)| (command.clone(), *seconds))
+        .collect();
+    cooldowns.sort();
+    let queue_limit = guild_settings.queue_rate_limit;
+
+    guild_settings.save()?;
+    drop(data);
+
+    create_response(
+        &ctx.http,
+        interaction,
+        ParrotMessage::Cooldowns {
+            cooldowns,
+            queue_limit,
+        },
+    )
+    .await
+}
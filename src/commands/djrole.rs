@@ -0,0 +1,41 @@
+use crate::{
+    errors::ParrotError,
+    guild::settings::{GuildSettings, GuildSettingsMap},
+    messaging::message::ParrotMessage,
+    messaging::messages::FAIL_GUILD_ONLY,
+    utils::create_response,
+};
+use serenity::{all::CommandInteraction, client::Context, prelude::Mentionable};
+
+pub async fn djrole(
+    ctx: &Context,
+    interaction: &mut CommandInteraction,
+) -> Result<(), ParrotError> {
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
+
+    let role_id = interaction
+        .data
+        .options
+        .first()
+        .and_then(|opt| opt.value.as_role_id());
+
+    let mut data = ctx.data.write().await;
+    let settings = data.get_mut::<GuildSettingsMap>().unwrap();
+
+    let guild_settings = settings
+        .entry(guild_id)
+        .or_insert_with(|| GuildSettings::new(guild_id));
+    guild_settings.dj_role = role_id;
+    guild_settings.save()?;
+    drop(data);
+
+    let message = match role_id {
+        Some(role_id) => ParrotMessage::DjRoleSet {
+            role: role_id.mention(),
+        },
+        None => ParrotMessage::DjRoleCleared,
+    };
+    create_response(&ctx.http, interaction, message).await
+}
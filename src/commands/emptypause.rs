@@ -0,0 +1,38 @@
+use crate::{
+    errors::ParrotError,
+    guild::settings::{GuildSettings, GuildSettingsMap},
+    handlers::voice_state::handle_listener_change,
+    messaging::message::ParrotMessage,
+    messaging::messages::FAIL_GUILD_ONLY,
+    utils::create_response,
+};
+use serenity::{all::CommandInteraction, client::Context};
+
+pub async fn emptypause(
+    ctx: &Context,
+    interaction: &mut CommandInteraction,
+) -> Result<(), ParrotError> {
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
+
+    let mut data = ctx.data.write().await;
+    let settings = data.get_mut::<GuildSettingsMap>().unwrap();
+
+    let guild_settings = settings
+        .entry(guild_id)
+        .or_insert_with(|| GuildSettings::new(guild_id));
+    guild_settings.toggle_pause_when_empty();
+    guild_settings.save()?;
+
+    let enabled = guild_settings.pause_when_empty;
+    drop(data);
+
+    handle_listener_change(ctx, guild_id).await;
+
+    if enabled {
+        create_response(&ctx.http, interaction, ParrotMessage::EmptyPauseOn).await
+    } else {
+        create_response(&ctx.http, interaction, ParrotMessage::EmptyPauseOff).await
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{
+    errors::ParrotError,
+    guild::settings::{GuildSettings, GuildSettingsMap},
+    messaging::message::ParrotMessage,
+    messaging::messages::{FAIL_GUILD_ONLY, FAIL_MISSING_SUBCOMMAND},
+    utils::create_response,
+};
+use serenity::{
+    all::{CommandDataOptionValue, CommandInteraction},
+    client::Context,
+};
+
+pub async fn filter(
+    ctx: &Context,
+    interaction: &mut CommandInteraction,
+) -> Result<(), ParrotError> {
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
+
+    let subcommand = interaction
+        .data
+        .options
+        .first()
+        .ok_or(ParrotError::Other(FAIL_MISSING_SUBCOMMAND))?;
+
+    let sub_options = match &subcommand.value {
+        CommandDataOptionValue::SubCommand(sub_options) => sub_options.as_slice(),
+        _ => &[],
+    };
+
+    let get_option = |name: &str| {
+        sub_options
+            .iter()
+            .find(|opt| opt.name == name)
+            .map(|opt| &opt.value)
+    };
+
+    let pattern = get_option("pattern")
+        .and_then(|value| value.as_str())
+        .map(|pattern| pattern.trim().to_string())
+        .filter(|pattern| !pattern.is_empty());
+    let block = get_option("block")
+        .and_then(|value| value.as_bool())
+        .unwrap_or(true);
+
+    let mut data = ctx.data.write().await;
+    let settings = data.get_mut::<GuildSettingsMap>().unwrap();
+
+    let guild_settings = settings
+        .entry(guild_id)
+        .or_insert_with(|| GuildSettings::new(guild_id));
+    let content_filter = &mut guild_settings.content_filter;
+
+    match subcommand.name.as_str() {
+        "title" => content_filter.set_title_pattern(pattern)?,
+        "channel" => content_filter.set_channel_pattern(pattern)?,
+        "livestreams" => content_filter.block_livestreams = block,
+        "agerestricted" => content_filter.block_age_restricted = block,
+        "duration" => {
+            content_filter.max_duration = get_option("minutes")
+                .and_then(|value| value.as_i64())
+                .filter(|minutes| *minutes > 0)
+                .map(|minutes| minutes as u64 * 60);
+        }
+        _ => {}
+    }
+
+    let filter = content_filter.clone();
+    guild_settings.save()?;
+    drop(data);
+
+    create_response(&ctx.http, interaction, ParrotMessage::Filters { filter }).await
+}
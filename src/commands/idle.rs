@@ -0,0 +1,78 @@
+use crate::{
+    errors::ParrotError,
+    guild::settings::{GuildSettings, GuildSettingsMap},
+    messaging::message::ParrotMessage,
+    messaging::messages::{FAIL_GUILD_ONLY, FAIL_MISSING_SUBCOMMAND, FAIL_MISSING_TIMEOUT},
+    utils::create_response,
+};
+use serenity::{
+    all::{CommandDataOptionValue, CommandInteraction},
+    client::Context,
+    prelude::Mentionable,
+};
+
+pub async fn idle(ctx: &Context, interaction: &mut CommandInteraction) -> Result<(), ParrotError> {
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
+
+    let subcommand = interaction
+        .data
+        .options
+        .first()
+        .ok_or(ParrotError::Other(FAIL_MISSING_SUBCOMMAND))?;
+
+    let CommandDataOptionValue::SubCommand(sub_options) = &subcommand.value else {
+        return Err(ParrotError::Other(FAIL_MISSING_SUBCOMMAND));
+    };
+
+    let get_option = |name: &str| {
+        sub_options
+            .iter()
+            .find(|opt| opt.name == name)
+            .map(|opt| &opt.value)
+    };
+
+    let mut data = ctx.data.write().await;
+    let settings = data.get_mut::<GuildSettingsMap>().unwrap();
+
+    let guild_settings = settings
+        .entry(guild_id)
+        .or_insert_with(|| GuildSettings::new(guild_id));
+
+    let message = match subcommand.name.as_str() {
+        "timeout" => {
+            let timeout = get_option("seconds")
+                .and_then(|value| value.as_i64())
+                .map(|seconds| seconds.max(0) as u64)
+                .ok_or(ParrotError::Other(FAIL_MISSING_TIMEOUT))?;
+
+            guild_settings.set_idle_timeout(timeout);
+
+            match timeout {
+                0 => ParrotMessage::IdleTimeoutNever,
+                _ => ParrotMessage::IdleTimeout { timeout },
+            }
+        }
+        _ => {
+            let announce = get_option("enabled")
+                .and_then(|value| value.as_bool())
+                .unwrap_or(true);
+            let channel_id = get_option("channel").and_then(|value| value.as_channel_id());
+
+            guild_settings.set_idle_announcements(announce, channel_id);
+
+            match announce {
+                true => ParrotMessage::IdleAnnounceOn {
+                    channel: channel_id.map(|channel_id| channel_id.mention()),
+                },
+                false => ParrotMessage::IdleAnnounceOff,
+            }
+        }
+    };
+
+    guild_settings.save()?;
+    drop(data);
+
+    create_response(&ctx.http, interaction, message).await
+}
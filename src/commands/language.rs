@@ -0,0 +1,48 @@
+use crate::{
+    errors::ParrotError,
+    guild::settings::{GuildSettings, GuildSettingsMap},
+    messaging::{
+        i18n::{with_locale, LANGUAGES},
+        message::ParrotMessage,
+        messages::FAIL_GUILD_ONLY,
+    },
+    utils::create_response,
+};
+use serenity::{all::CommandInteraction, client::Context};
+
+pub async fn language(
+    ctx: &Context,
+    interaction: &mut CommandInteraction,
+) -> Result<(), ParrotError> {
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
+
+    let language = interaction
+        .data
+        .options
+        .first()
+        .and_then(|opt| opt.value.as_str())
+        .and_then(|code| LANGUAGES.iter().find(|(locale, _)| *locale == code));
+
+    let mut data = ctx.data.write().await;
+    let settings = data.get_mut::<GuildSettingsMap>().unwrap();
+
+    let guild_settings = settings
+        .entry(guild_id)
+        .or_insert_with(|| GuildSettings::new(guild_id));
+    guild_settings.locale = language.map(|(locale, _)| locale.to_string());
+    guild_settings.save()?;
+    drop(data);
+
+    // confirm in the language that was just picked
+    let locale = language.map_or(interaction.locale.clone(), |(locale, _)| locale.to_string());
+    let message = ParrotMessage::Language {
+        name: language.map(|(_, name)| *name),
+    };
+    with_locale(
+        Some(&locale),
+        create_response(&ctx.http, interaction, message),
+    )
+    .await
+}
@@ -1,17 +1,24 @@
-use crate::{errors::ParrotError, messaging::message::ParrotMessage, utils::create_response};
+use crate::{
+    commands::cancel::cancel_imports,
+    errors::ParrotError,
+    messaging::message::ParrotMessage,
+    messaging::messages::{FAIL_GUILD_ONLY, FAIL_LEAVE, FAIL_VOICE_MANAGER},
+    utils::create_response,
+};
 use serenity::{all::CommandInteraction, client::Context};
 
 pub async fn leave(ctx: &Context, interaction: &mut CommandInteraction) -> Result<(), ParrotError> {
-    let guild_id = interaction.guild_id.ok_or(ParrotError::Other(
-        "This command can only be used in a server",
-    ))?;
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
     let manager = songbird::get(ctx)
         .await
-        .ok_or(ParrotError::Other("Voice manager not configured"))?;
+        .ok_or(ParrotError::Other(FAIL_VOICE_MANAGER))?;
+    cancel_imports(&ctx.data, guild_id).await;
     manager
         .remove(guild_id)
         .await
-        .map_err(|_| ParrotError::Other("Failed to leave voice channel"))?;
+        .map_err(|_| ParrotError::Other(FAIL_LEAVE))?;
 
     create_response(&ctx.http, interaction, ParrotMessage::Leaving).await
 }
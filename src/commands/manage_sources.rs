@@ -1,29 +1,32 @@
 use crate::{
     errors::ParrotError,
     guild::settings::{GuildSettings, GuildSettingsMap},
+    handlers::audit_log::{diff_values, log_settings_change},
+    messaging::i18n::tr,
     messaging::messages::{
         DOMAIN_FORM_ALLOWED_PLACEHOLDER, DOMAIN_FORM_ALLOWED_TITLE, DOMAIN_FORM_BANNED_PLACEHOLDER,
-        DOMAIN_FORM_BANNED_TITLE, DOMAIN_FORM_TITLE,
+        DOMAIN_FORM_BANNED_TITLE, DOMAIN_FORM_TITLE, FAIL_GUILD_ONLY, FAIL_GUILD_SETTINGS,
     },
 };
 use serenity::{
     all::{
         ActionRowComponent, CommandInteraction, CreateActionRow, CreateInputText,
-        CreateInteractionResponse, CreateModal, InputTextStyle,
+        CreateInteractionResponse, CreateInteractionResponseMessage, CreateModal, InputTextStyle,
     },
+    builder::CreateEmbed,
     client::Context,
     futures::StreamExt,
 };
 
 pub async fn allow(ctx: &Context, interaction: &mut CommandInteraction) -> Result<(), ParrotError> {
-    let guild_id = interaction.guild_id.ok_or(ParrotError::Other(
-        "This command can only be used in a server",
-    ))?;
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
 
     let mut data = ctx.data.write().await;
     let settings = data
         .get_mut::<GuildSettingsMap>()
-        .ok_or(ParrotError::Other("Guild settings not initialized"))?;
+        .ok_or(ParrotError::Other(FAIL_GUILD_SETTINGS))?;
 
     let guild_settings = settings
         .entry(guild_id)
@@ -48,23 +51,23 @@ pub async fn allow(ctx: &Context, interaction: &mut CommandInteraction) -> Resul
 
     let allowed_input = CreateInputText::new(
         InputTextStyle::Paragraph,
-        DOMAIN_FORM_ALLOWED_TITLE,
+        tr(DOMAIN_FORM_ALLOWED_TITLE),
         "allowed_domains",
     )
-    .placeholder(DOMAIN_FORM_ALLOWED_PLACEHOLDER)
+    .placeholder(tr(DOMAIN_FORM_ALLOWED_PLACEHOLDER))
     .value(allowed_str)
     .required(false);
 
     let banned_input = CreateInputText::new(
         InputTextStyle::Paragraph,
-        DOMAIN_FORM_BANNED_TITLE,
+        tr(DOMAIN_FORM_BANNED_TITLE),
         "banned_domains",
     )
-    .placeholder(DOMAIN_FORM_BANNED_PLACEHOLDER)
+    .placeholder(tr(DOMAIN_FORM_BANNED_PLACEHOLDER))
     .value(banned_str)
     .required(false);
 
-    let modal = CreateModal::new("manage_domains", DOMAIN_FORM_TITLE).components(vec![
+    let modal = CreateModal::new("manage_domains", tr(DOMAIN_FORM_TITLE)).components(vec![
         CreateActionRow::InputText(allowed_input),
         CreateActionRow::InputText(banned_input),
     ]);
@@ -98,31 +101,66 @@ pub async fn allow(ctx: &Context, interaction: &mut CommandInteraction) -> Resul
             continue;
         };
 
+        let allowed_before = guild_settings.allowed_domains.clone();
+        let banned_before = guild_settings.banned_domains.clone();
+
+        let mut result = Ok(());
         for input in inputs.iter() {
             if let ActionRowComponent::InputText(it) = input {
-                if it.custom_id == "allowed_domains" {
-                    if let Some(ref value) = it.value {
-                        guild_settings.set_allowed_domains(value);
-                    }
-                }
-
-                if it.custom_id == "banned_domains" {
-                    if let Some(ref value) = it.value {
-                        guild_settings.set_banned_domains(value);
-                    }
-                }
+                let Some(ref value) = it.value else {
+                    continue;
+                };
+
+                let update = match it.custom_id.as_str() {
+                    "allowed_domains" => guild_settings.set_allowed_domains(value),
+                    "banned_domains" => guild_settings.set_banned_domains(value),
+                    _ => Ok(()),
+                };
+                result = result.and(update);
             }
         }
 
+        // keep the saved lists untouched if anything typed in was not a domain
+        if let Err(err) = result {
+            guild_settings.allowed_domains = allowed_before;
+            guild_settings.banned_domains = banned_before;
+            drop(data);
+
+            let response = CreateInteractionResponse::Message(
+                CreateInteractionResponseMessage::new()
+                    .add_embed(CreateEmbed::new().description(err.to_string()))
+                    .ephemeral(true),
+            );
+            int.create_response(&ctx.http, response).await.ok();
+            continue;
+        }
+
         guild_settings.update_domains();
         if let Err(err) = guild_settings.save() {
             eprintln!("[ERROR] Failed to save guild settings: {}", err);
         }
 
+        let changes = [
+            diff_values(
+                tr(DOMAIN_FORM_ALLOWED_TITLE),
+                &allowed_before,
+                &guild_settings.allowed_domains,
+            ),
+            diff_values(
+                tr(DOMAIN_FORM_BANNED_TITLE),
+                &banned_before,
+                &guild_settings.banned_domains,
+            ),
+        ];
+        drop(data);
+
         // it's now safe to close the modal, so send a response to it
         int.create_response(&ctx.http, CreateInteractionResponse::Acknowledge)
             .await
             .ok();
+
+        let changes = changes.into_iter().flatten().collect();
+        log_settings_change(ctx, guild_id, int.user.id, "managesources", changes).await;
     }
 
     Ok(())
@@ -1,9 +1,24 @@
+pub mod always_on;
+pub mod audit_log;
+pub mod autoleave;
 pub mod autopause;
+pub mod broadcast;
+pub mod cache;
+pub mod cancel;
+pub mod channels;
 pub mod clear;
+pub mod cooldown;
+pub mod djrole;
+pub mod emptypause;
+pub mod filter;
+pub mod idle;
+pub mod language;
 pub mod leave;
 pub mod manage_sources;
+pub mod musicban;
 pub mod now_playing;
 pub mod pause;
+pub mod permissions;
 pub mod play;
 pub mod queue;
 pub mod remove;
@@ -12,7 +27,10 @@ pub mod resume;
 pub mod seek;
 pub mod shuffle;
 pub mod skip;
+pub mod stagetopic;
 pub mod stop;
 pub mod summon;
 pub mod version;
+pub mod vote;
+pub mod votepercent;
 pub mod voteskip;
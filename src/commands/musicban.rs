@@ -0,0 +1,107 @@
+use crate::{
+    errors::{verify, ParrotError},
+    guild::settings::{BanScope, GuildSettings, GuildSettingsMap, MusicBan},
+    messaging::message::ParrotMessage,
+    messaging::messages::{FAIL_GUILD_ONLY, FAIL_MISSING_USER, FAIL_MUSIC_BAN_PROTECTED},
+    utils::create_response,
+};
+use serenity::{all::CommandInteraction, client::Context, model::Timestamp, prelude::Mentionable};
+
+pub async fn musicban(
+    ctx: &Context,
+    interaction: &mut CommandInteraction,
+) -> Result<(), ParrotError> {
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
+
+    let get_option = |name: &str| {
+        interaction
+            .data
+            .options
+            .iter()
+            .find(|opt| opt.name == name)
+            .map(|opt| &opt.value)
+    };
+
+    let user_id = get_option("user")
+        .and_then(|value| value.as_user_id())
+        .ok_or(ParrotError::Other(FAIL_MISSING_USER))?;
+
+    // admins could lift the ban themselves and bots don't use commands anyway
+    let resolved = &interaction.data.resolved;
+    let is_bot = resolved.users.get(&user_id).is_some_and(|user| user.bot);
+    let is_admin = resolved
+        .members
+        .get(&user_id)
+        .and_then(|member| member.permissions)
+        .is_some_and(|permissions| permissions.administrator());
+    verify(
+        !is_bot && !is_admin,
+        ParrotError::Other(FAIL_MUSIC_BAN_PROTECTED),
+    )?;
+
+    let scope = get_option("scope")
+        .and_then(|value| value.as_str())
+        .and_then(BanScope::from_id)
+        .unwrap_or(BanScope::All);
+
+    let now = Timestamp::now().unix_timestamp();
+    let expires = get_option("minutes")
+        .and_then(|value| value.as_i64())
+        .map(|minutes| now + minutes.max(1) * 60);
+    let ban = MusicBan { scope, expires };
+
+    let mut data = ctx.data.write().await;
+    let settings = data.get_mut::<GuildSettingsMap>().unwrap();
+
+    let guild_settings = settings
+        .entry(guild_id)
+        .or_insert_with(|| GuildSettings::new(guild_id));
+    guild_settings.ban_user(user_id, ban, now);
+    guild_settings.save()?;
+    drop(data);
+
+    create_response(
+        &ctx.http,
+        interaction,
+        ParrotMessage::MusicBanned {
+            user: user_id.mention(),
+            ban,
+        },
+    )
+    .await
+}
+
+pub async fn musicunban(
+    ctx: &Context,
+    interaction: &mut CommandInteraction,
+) -> Result<(), ParrotError> {
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
+
+    let user_id = interaction
+        .data
+        .options
+        .first()
+        .and_then(|opt| opt.value.as_user_id())
+        .ok_or(ParrotError::Other(FAIL_MISSING_USER))?;
+
+    let mut data = ctx.data.write().await;
+    let settings = data.get_mut::<GuildSettingsMap>().unwrap();
+
+    let guild_settings = settings
+        .entry(guild_id)
+        .or_insert_with(|| GuildSettings::new(guild_id));
+    let was_banned = guild_settings.unban_user(user_id, Timestamp::now().unix_timestamp());
+    guild_settings.save()?;
+    drop(data);
+
+    let user = user_id.mention();
+    let message = match was_banned {
+        true => ParrotMessage::MusicUnbanned { user },
+        false => ParrotMessage::MusicNotBanned { user },
+    };
+    create_response(&ctx.http, interaction, message).await
+}
@@ -1,19 +1,28 @@
 use crate::{
+    commands::voteskip::VOTESKIP_BUTTON_ID,
     errors::ParrotError,
-    utils::{create_embed_response, create_now_playing_embed},
+    messaging::i18n::tr,
+    messaging::messages::{FAIL_GUILD_ONLY, FAIL_VOICE_MANAGER, SKIP_VOTE_BUTTON, SKIP_VOTE_EMOJI},
+    utils::create_now_playing_embed,
+};
+use serenity::{
+    all::{
+        ButtonStyle, CommandInteraction, CreateActionRow, CreateButton, CreateInteractionResponse,
+        CreateInteractionResponseMessage,
+    },
+    client::Context,
 };
-use serenity::{all::CommandInteraction, client::Context};
 
 pub async fn now_playing(
     ctx: &Context,
     interaction: &mut CommandInteraction,
 ) -> Result<(), ParrotError> {
-    let guild_id = interaction.guild_id.ok_or(ParrotError::Other(
-        "This command can only be used in a server",
-    ))?;
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
     let manager = songbird::get(ctx)
         .await
-        .ok_or(ParrotError::Other("Voice manager not configured"))?;
+        .ok_or(ParrotError::Other(FAIL_VOICE_MANAGER))?;
     let call = manager.get(guild_id).ok_or(ParrotError::NotConnected)?;
 
     let handler = call.lock().await;
@@ -23,5 +32,20 @@ pub async fn now_playing(
         .ok_or(ParrotError::NothingPlaying)?;
 
     let embed = create_now_playing_embed(&track).await;
-    create_embed_response(&ctx.http, interaction, embed).await
+    drop(handler);
+
+    let response = CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new()
+            .add_embed(embed)
+            .components(vec![build_vote_btn()]),
+    );
+    interaction.create_response(&ctx.http, response).await?;
+    Ok(())
+}
+
+fn build_vote_btn() -> CreateActionRow {
+    let button = CreateButton::new(VOTESKIP_BUTTON_ID)
+        .label(format!("{}{}", tr(SKIP_VOTE_EMOJI), tr(SKIP_VOTE_BUTTON)))
+        .style(ButtonStyle::Secondary);
+    CreateActionRow::Buttons(vec![button])
 }
@@ -1,24 +1,29 @@
 use crate::{
     errors::{verify, ParrotError},
+    handlers::voice_state::forget_auto_pause,
     messaging::message::ParrotMessage,
+    messaging::messages::{FAIL_GUILD_ONLY, FAIL_PAUSE, FAIL_VOICE_MANAGER},
     utils::create_response,
 };
 use serenity::{all::CommandInteraction, client::Context};
 
 pub async fn pause(ctx: &Context, interaction: &mut CommandInteraction) -> Result<(), ParrotError> {
-    let guild_id = interaction.guild_id.ok_or(ParrotError::Other(
-        "This command can only be used in a server",
-    ))?;
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
     let manager = songbird::get(ctx)
         .await
-        .ok_or(ParrotError::Other("Voice manager not configured"))?;
+        .ok_or(ParrotError::Other(FAIL_VOICE_MANAGER))?;
     let call = manager.get(guild_id).ok_or(ParrotError::NotConnected)?;
 
+    // a user took over, so the bot shouldn't resume on its own anymore
+    forget_auto_pause(&ctx.data, guild_id).await;
+
     let handler = call.lock().await;
     let queue = handler.queue();
 
     verify(!queue.is_empty(), ParrotError::NothingPlaying)?;
-    verify(queue.pause(), ParrotError::Other("Failed to pause"))?;
+    verify(queue.pause(), ParrotError::Other(FAIL_PAUSE))?;
 
     create_response(&ctx.http, interaction, ParrotMessage::Pause).await
 }
@@ -0,0 +1,208 @@
+use crate::{
+    errors::ParrotError,
+    guild::settings::{CommandPolicy, GuildSettings, GuildSettingsMap},
+    messaging::i18n::tr,
+    messaging::messages::{
+        FAIL_GUILD_ONLY, PERMISSIONS_EMPTY, PERMISSIONS_EXPIRED, PERMISSIONS_PICK_COMMAND,
+        PERMISSIONS_PICK_POLICY, PERMISSIONS_TITLE, POLICY_DEFAULT,
+    },
+};
+use serenity::{
+    all::{
+        CommandInteraction, ComponentInteractionDataKind, CreateActionRow,
+        CreateInteractionResponse, CreateInteractionResponseMessage, CreateSelectMenu,
+        CreateSelectMenuKind, CreateSelectMenuOption, EditMessage,
+    },
+    builder::CreateEmbed,
+    client::Context,
+    futures::StreamExt,
+    model::{application::Command, id::GuildId},
+};
+use std::{collections::HashMap, fmt::Write, time::Duration};
+
+const PERMISSIONS_TIMEOUT: u64 = 300;
+const SELECT_MENU_MAX_OPTIONS: usize = 25;
+const COMMAND_SELECT_ID: &str = "permissions_command";
+const POLICY_SELECT_ID: &str = "permissions_policy";
+const DEFAULT_POLICY_ID: &str = "default";
+
+pub async fn permissions(
+    ctx: &Context,
+    interaction: &mut CommandInteraction,
+) -> Result<(), ParrotError> {
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
+
+    // locking admins out of this very menu would make it impossible to undo, and Discord
+    // hides commands that need a permission from everyone else whatever policy is picked
+    let mut commands: Vec<String> = Command::get_global_commands(&ctx.http)
+        .await?
+        .into_iter()
+        .filter(|command| {
+            command
+                .default_member_permissions
+                .is_none_or(|permissions| permissions.is_empty())
+        })
+        .map(|command| command.name)
+        .filter(|name| name != "permissions")
+        .collect();
+    commands.sort();
+
+    let policies = get_policies(ctx, guild_id).await;
+    let response = CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new()
+            .add_embed(create_overview_embed(&policies))
+            .components(build_command_selects(&commands)),
+    );
+    interaction.create_response(&ctx.http, response).await?;
+
+    let mut message = interaction.get_response(&ctx.http).await?;
+    let mut collector = message
+        .await_component_interactions(ctx)
+        .author_id(interaction.user.id)
+        .timeout(Duration::from_secs(PERMISSIONS_TIMEOUT))
+        .stream();
+
+    let mut selected: Option<String> = None;
+
+    while let Some(mci) = collector.next().await {
+        let ComponentInteractionDataKind::StringSelect { values } = &mci.data.kind else {
+            continue;
+        };
+        let Some(value) = values.first() else {
+            continue;
+        };
+
+        let (embed, components) = match mci.data.custom_id.as_str() {
+            id if id.starts_with(COMMAND_SELECT_ID) => {
+                let current = get_policies(ctx, guild_id).await.get(value).copied();
+                selected = Some(value.clone());
+                (
+                    create_policy_embed(value, current),
+                    vec![build_policy_select(current)],
+                )
+            }
+            POLICY_SELECT_ID => {
+                if let Some(command) = selected.take() {
+                    set_policy(ctx, guild_id, &command, CommandPolicy::from_id(value)).await?;
+                }
+                let policies = get_policies(ctx, guild_id).await;
+                (
+                    create_overview_embed(&policies),
+                    build_command_selects(&commands),
+                )
+            }
+            _ => continue,
+        };
+
+        let response = CreateInteractionResponse::UpdateMessage(
+            CreateInteractionResponseMessage::new()
+                .add_embed(embed)
+                .components(components),
+        );
+        mci.create_response(&ctx, response).await?;
+    }
+
+    let edit = EditMessage::new()
+        .embed(CreateEmbed::new().description(tr(PERMISSIONS_EXPIRED)))
+        .components(vec![]);
+    if let Err(e) = message.edit(&ctx.http, edit).await {
+        eprintln!("[WARN] Failed to edit permissions message: {}", e);
+    }
+
+    Ok(())
+}
+
+async fn get_policies(ctx: &Context, guild_id: GuildId) -> HashMap<String, CommandPolicy> {
+    let data = ctx.data.read().await;
+    let settings = data.get::<GuildSettingsMap>().unwrap();
+    settings
+        .get(&guild_id)
+        .map(|guild_settings| guild_settings.command_policies.clone())
+        .unwrap_or_default()
+}
+
+async fn set_policy(
+    ctx: &Context,
+    guild_id: GuildId,
+    command: &str,
+    policy: Option<CommandPolicy>,
+) -> Result<(), ParrotError> {
+    let mut data = ctx.data.write().await;
+    let settings = data.get_mut::<GuildSettingsMap>().unwrap();
+
+    let guild_settings = settings
+        .entry(guild_id)
+        .or_insert_with(|| GuildSettings::new(guild_id));
+    guild_settings.set_command_policy(command, policy);
+    guild_settings.save()
+}
+
+fn create_overview_embed(policies: &HashMap<String, CommandPolicy>) -> CreateEmbed {
+    let mut overrides: Vec<_> = policies.iter().collect();
+    overrides.sort_by_key(|(command, _)| *command);
+
+    let description = match overrides.is_empty() {
+        true => tr(PERMISSIONS_EMPTY).to_string(),
+        false => overrides
+            .iter()
+            .fold(String::new(), |mut description, (command, policy)| {
+                let _ = writeln!(description, "`/{}` • **{}**", command, policy);
+                description
+            }),
+    };
+
+    CreateEmbed::new()
+        .title(tr(PERMISSIONS_TITLE))
+        .description(description)
+}
+
+fn create_policy_embed(command: &str, current: Option<CommandPolicy>) -> CreateEmbed {
+    let current = current.map_or(tr(POLICY_DEFAULT).to_string(), |policy| policy.to_string());
+
+    CreateEmbed::new()
+        .title(tr(PERMISSIONS_TITLE))
+        .description(format!(
+            "{} `/{}`? (**{}**)",
+            tr(PERMISSIONS_PICK_POLICY),
+            command,
+            current
+        ))
+}
+
+/// Discord caps select menus at 25 options, so larger command lists are spread across menus.
+fn build_command_selects(commands: &[String]) -> Vec<CreateActionRow> {
+    commands
+        .chunks(SELECT_MENU_MAX_OPTIONS)
+        .enumerate()
+        .map(|(i, chunk)| {
+            let options = chunk
+                .iter()
+                .map(|name| CreateSelectMenuOption::new(format!("/{}", name), name))
+                .collect();
+
+            let menu = CreateSelectMenu::new(
+                format!("{}_{}", COMMAND_SELECT_ID, i),
+                CreateSelectMenuKind::String { options },
+            )
+            .placeholder(tr(PERMISSIONS_PICK_COMMAND));
+            CreateActionRow::SelectMenu(menu)
+        })
+        .collect()
+}
+
+fn build_policy_select(current: Option<CommandPolicy>) -> CreateActionRow {
+    let mut options = vec![
+        CreateSelectMenuOption::new(tr(POLICY_DEFAULT), DEFAULT_POLICY_ID)
+            .default_selection(current.is_none()),
+    ];
+
+    options.extend(CommandPolicy::ALL.iter().map(|policy| {
+        CreateSelectMenuOption::new(policy.to_string(), policy.id())
+            .default_selection(current == Some(*policy))
+    }));
+
+    let menu = CreateSelectMenu::new(POLICY_SELECT_ID, CreateSelectMenuKind::String { options });
+    CreateActionRow::SelectMenu(menu)
+}
@@ -1,31 +1,85 @@
 use crate::{
-    commands::{skip::force_skip_top_track, summon::summon},
+    commands::{
+        cancel::{forget_import, register_import},
+        skip::force_skip_top_track,
+        summon::summon,
+    },
     errors::{verify, ParrotError},
-    guild::settings::{GuildSettings, GuildSettingsMap},
+    guild::{
+        filter::ContentFilter,
+        settings::{GuildSettings, GuildSettingsMap},
+    },
     handlers::track_end::update_queue_messages,
+    messaging::i18n::tr,
     messaging::message::ParrotMessage,
     messaging::messages::{
-        PLAY_QUEUE, PLAY_TOP, SPOTIFY_AUTH_FAILED, TRACK_DURATION, TRACK_TIME_TO_PLAY,
+        FAIL_FETCH_PLAYLIST, FAIL_GUILD_ONLY, FAIL_MISSING_QUERY, FAIL_PLAYLIST_RANGE,
+        FAIL_QUERY_PARSING, FAIL_ROTATE_QUEUE, FAIL_VOICE_CALL, FAIL_VOICE_MANAGER,
+        PLAYLIST_CANCEL_BUTTON, PLAYLIST_CONFIRM_BUTTON, PLAY_QUEUE, PLAY_TOP, SPOTIFY_AUTH_FAILED,
+        TRACK_DURATION, TRACK_TIME_TO_PLAY,
     },
     sources::{
+        links::{normalize_url, resolve_short_link},
         spotify::{Spotify, SPOTIFY},
-        youtube::{YouTube, YouTubeRestartable},
+        youtube::{PlaylistRange, ResolvedTrack, UnavailableReason, YouTube, YouTubeRestartable},
     },
     utils::{
-        compare_domains, create_now_playing_embed, create_response, edit_embed_response,
-        edit_response, get_human_readable_timestamp,
+        create_now_playing_embed, create_response, edit_embed_response, edit_response,
+        get_human_readable_timestamp, Debouncer,
     },
 };
+use lazy_static::lazy_static;
 use serenity::{
-    all::{CommandDataOptionValue, CommandInteraction, CreateEmbedFooter},
+    all::{
+        ButtonStyle, CommandDataOptionValue, CommandInteraction, CreateActionRow, CreateButton,
+        CreateEmbedFooter, CreateInteractionResponse, EditInteractionResponse,
+    },
     builder::CreateEmbed,
     client::Context,
-    prelude::Mutex,
+    futures::{stream, StreamExt},
+    http::Http,
+    model::{
+        channel::Message,
+        id::{GuildId, UserId},
+    },
+    prelude::{Mutex, RwLock, TypeMap},
+};
+use songbird::{
+    input::{AuxMetadata, Input},
+    tracks::{Track, TrackHandle},
+    Call,
 };
-use songbird::{input::AuxMetadata, tracks::TrackHandle, Call};
-use std::{cmp::Ordering, error::Error as StdError, sync::Arc, time::Duration};
+use std::{cmp::Ordering, env, error::Error as StdError, sync::Arc, time::Duration};
+use tokio::task::JoinHandle;
+use tokio_util::{sync::CancellationToken, task::AbortOnDropHandle};
 use url::Url;
 
+const CANCEL_IMPORT_BUTTON_ID: &str = "cancel_import";
+const CONFIRM_IMPORT_BUTTON_ID: &str = "confirm_import";
+const CONFIRM_IMPORT_TIMEOUT: u64 = 60;
+const DEFAULT_PLAYLIST_CONFIRM_THRESHOLD: usize = 200;
+
+lazy_static! {
+    /// Playlists with more entries than this need to be confirmed before being imported,
+    /// a value of zero disables the confirmation altogether.
+    static ref PLAYLIST_CONFIRM_THRESHOLD: usize = env::var("PLAYLIST_CONFIRM_THRESHOLD")
+        .ok()
+        .and_then(|threshold| threshold.parse().ok())
+        .unwrap_or(DEFAULT_PLAYLIST_CONFIRM_THRESHOLD);
+}
+
+/// How many playlist entries are resolved through yt-dlp at the same time.
+const PLAYLIST_CONCURRENCY: usize = 8;
+
+/// Keyword searches go through YouTube, so they fall under its domain rules.
+const SEARCH_DOMAIN: &str = "youtube.com";
+
+/// Minimum time between queue message refreshes while a playlist is being loaded.
+const QUEUE_REFRESH_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Minimum time between edits of the progress message while a playlist is being loaded.
+const PROGRESS_REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+
 // This crate requires panic=unwind for safe metadata access from songbird tracks.
 // The catch_unwind pattern in get_track_metadata() will not work with panic=abort.
 #[cfg(panic = "abort")]
@@ -38,18 +92,29 @@ compile_error!("This crate requires panic=unwind due to songbird metadata access
 /// workaround to safely handle tracks that may have been created without our metadata
 /// or with a different type.
 ///
-/// All tracks queued via our `enqueue_track` function store `AuxMetadata` as user data.
+/// All tracks queued via our `enqueue_track` function store [`TrackData`] as user data.
 ///
 /// Note: This approach has limitations:
 /// - May not work with `-C panic=abort` compilation (compile-time error added)
 /// - Has some performance overhead
 /// - Should be replaced if songbird adds a fallible data access method
 pub fn get_track_metadata(track: &TrackHandle) -> Option<AuxMetadata> {
-    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        let data: Arc<AuxMetadata> = track.data();
-        (*data).clone()
-    }))
-    .ok()
+    get_track_data(track).map(|data| data.metadata.clone())
+}
+
+/// Returns who queued a track, if it was queued by a user rather than by the bot itself.
+pub fn get_track_requester(track: &TrackHandle) -> Option<UserId> {
+    get_track_data(track).and_then(|data| data.requester)
+}
+
+fn get_track_data(track: &TrackHandle) -> Option<Arc<TrackData>> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| track.data::<TrackData>())).ok()
+}
+
+/// User data stored alongside every track queued by the bot.
+pub struct TrackData {
+    pub metadata: AuxMetadata,
+    pub requester: Option<UserId>,
 }
 
 #[derive(Clone, Copy)]
@@ -72,12 +137,10 @@ pub enum QueryType {
 
 pub async fn play(ctx: &Context, interaction: &mut CommandInteraction) -> Result<(), ParrotError> {
     let args = interaction.data.options.clone();
-    let first_arg = args
-        .first()
-        .ok_or(ParrotError::Other("Missing query argument"))?;
+    let first_arg = args.first().ok_or(ParrotError::Other(FAIL_MISSING_QUERY))?;
 
-    let (mode, url) = match &first_arg.value {
-        CommandDataOptionValue::String(s) => (Mode::End, s.clone()),
+    let (mode, url, range) = match &first_arg.value {
+        CommandDataOptionValue::String(s) => (Mode::End, s.clone(), PlaylistRange::default()),
         CommandDataOptionValue::SubCommand(sub_options) => {
             let mode = match first_arg.name.as_str() {
                 "next" => Mode::Next,
@@ -88,31 +151,57 @@ pub async fn play(ctx: &Context, interaction: &mut CommandInteraction) -> Result
                 _ => Mode::End,
             };
             let query = sub_options
-                .first()
+                .iter()
+                .find(|opt| opt.name == "query")
                 .and_then(|opt| opt.value.as_str())
                 .unwrap_or("")
                 .to_string();
-            (mode, query)
+
+            let get_position = |name: &str| {
+                sub_options
+                    .iter()
+                    .find(|opt| opt.name == name)
+                    .and_then(|opt| opt.value.as_i64())
+                    .map(|value| value.max(1) as usize)
+            };
+
+            let range = PlaylistRange {
+                start: get_position("start"),
+                end: get_position("end"),
+                limit: get_position("limit"),
+            };
+            (mode, query, range)
         }
-        _ => (Mode::End, String::new()),
+        _ => (Mode::End, String::new(), PlaylistRange::default()),
     };
 
+    if let (Some(start), Some(end)) = (range.start, range.end) {
+        verify(end >= start, ParrotError::Other(FAIL_PLAYLIST_RANGE))?;
+    }
+
     let url = url.as_str();
 
-    let guild_id = interaction.guild_id.ok_or(ParrotError::Other(
-        "This command can only be used in a server",
-    ))?;
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
 
     let manager = songbird::get(ctx)
         .await
-        .ok_or(ParrotError::Other("Voice manager not configured"))?;
+        .ok_or(ParrotError::Other(FAIL_VOICE_MANAGER))?;
 
     // try to join a voice channel if not in one just yet
     summon(ctx, interaction, false).await?;
 
     let call = manager
         .get(guild_id)
-        .ok_or(ParrotError::Other("Failed to get voice call"))?;
+        .ok_or(ParrotError::Other(FAIL_VOICE_CALL))?;
+
+    // short links and alternative hosts would otherwise slip past the domain rules
+    let url = match Url::parse(url) {
+        Ok(url_data) => normalize_url(resolve_short_link(url_data).await).to_string(),
+        Err(_) => url.to_string(),
+    };
+    let url = url.as_str();
 
     // determine whether this is a link or a query string
     let query_type = match Url::parse(url) {
@@ -129,17 +218,7 @@ pub async fn play(ctx: &Context, interaction: &mut CommandInteraction) -> Result
                     .entry(guild_id)
                     .or_insert_with(|| GuildSettings::new(guild_id));
 
-                let is_allowed = guild_settings
-                    .allowed_domains
-                    .iter()
-                    .any(|d| compare_domains(d, other));
-
-                let is_banned = guild_settings
-                    .banned_domains
-                    .iter()
-                    .any(|d| compare_domains(d, other));
-
-                if is_banned || (guild_settings.banned_domains.is_empty() && !is_allowed) {
+                if !guild_settings.is_domain_allowed(other) {
                     return create_response(
                         &ctx.http,
                         interaction,
@@ -161,15 +240,12 @@ pub async fn play(ctx: &Context, interaction: &mut CommandInteraction) -> Result
                 .entry(guild_id)
                 .or_insert_with(|| GuildSettings::new(guild_id));
 
-            if guild_settings.banned_domains.contains("youtube.com")
-                || (guild_settings.banned_domains.is_empty()
-                    && !guild_settings.allowed_domains.contains("youtube.com"))
-            {
+            if !guild_settings.is_domain_allowed(SEARCH_DOMAIN) {
                 return create_response(
                     &ctx.http,
                     interaction,
                     ParrotMessage::PlayDomainBanned {
-                        domain: "youtube.com".to_string(),
+                        domain: SEARCH_DOMAIN.to_string(),
                     },
                 )
                 .await;
@@ -179,10 +255,16 @@ pub async fn play(ctx: &Context, interaction: &mut CommandInteraction) -> Result
         }
     };
 
-    let query_type = verify(
-        query_type,
-        ParrotError::Other("Something went wrong while parsing your query!"),
-    )?;
+    let query_type = verify(query_type, ParrotError::Other(FAIL_QUERY_PARSING))?;
+
+    let filter = {
+        let data = ctx.data.read().await;
+        let settings = data.get::<GuildSettingsMap>().unwrap();
+        settings
+            .get(&guild_id)
+            .map(|guild_settings| guild_settings.content_filter.clone())
+            .unwrap_or_default()
+    };
 
     // reply with a temporary message while we fetch the source
     // needed because interactions must be replied within 3s and queueing takes longer
@@ -192,182 +274,70 @@ pub async fn play(ctx: &Context, interaction: &mut CommandInteraction) -> Result
     let queue_was_empty = handler.queue().is_empty();
     drop(handler);
 
-    match mode {
-        Mode::End => match query_type.clone() {
-            QueryType::Keywords(_) | QueryType::VideoLink(_) => {
-                let queue = enqueue_track(&call, &query_type).await?;
-                update_queue_messages(&ctx.http, &ctx.data, &queue, guild_id).await;
-            }
-            QueryType::PlaylistLink(url) => {
-                let urls = YouTubeRestartable::ytdl_playlist(&url, mode)
-                    .await
-                    .ok_or(ParrotError::Other("failed to fetch playlist"))?;
-
-                let mut failed_count = 0;
-                for url in urls.iter() {
-                    match enqueue_track(&call, &QueryType::VideoLink(url.to_string())).await {
-                        Ok(queue) => {
-                            update_queue_messages(&ctx.http, &ctx.data, &queue, guild_id).await;
-                        }
-                        Err(e) => {
-                            eprintln!("[WARN] Failed to enqueue track {}: {}", url, e);
-                            failed_count += 1;
-                        }
-                    }
-                }
-                if failed_count > 0 {
-                    eprintln!(
-                        "[WARN] Playlist: {}/{} tracks failed to enqueue",
-                        failed_count,
-                        urls.len()
-                    );
-                }
-            }
-            QueryType::KeywordList(keywords_list) => {
-                for keywords in keywords_list.iter() {
-                    let queue =
-                        enqueue_track(&call, &QueryType::Keywords(keywords.to_string())).await?;
-                    update_queue_messages(&ctx.http, &ctx.data, &queue, guild_id).await;
-                }
-            }
-        },
-        Mode::Next => match query_type.clone() {
-            QueryType::Keywords(_) | QueryType::VideoLink(_) => {
-                let queue = insert_track(&call, &query_type, 1).await?;
-                update_queue_messages(&ctx.http, &ctx.data, &queue, guild_id).await;
-            }
-            QueryType::PlaylistLink(url) => {
-                let urls = YouTubeRestartable::ytdl_playlist(&url, mode)
-                    .await
-                    .ok_or(ParrotError::Other("failed to fetch playlist"))?;
-
-                let total = urls.len();
-                let mut failed_count = 0;
-                for (idx, url) in urls.into_iter().enumerate() {
-                    match insert_track(&call, &QueryType::VideoLink(url.clone()), idx + 1).await {
-                        Ok(queue) => {
-                            update_queue_messages(&ctx.http, &ctx.data, &queue, guild_id).await;
-                        }
-                        Err(e) => {
-                            eprintln!("[WARN] Failed to insert track {}: {}", url, e);
-                            failed_count += 1;
-                        }
-                    }
-                }
-                if failed_count > 0 {
-                    eprintln!(
-                        "[WARN] Playlist: {}/{} tracks failed to insert",
-                        failed_count, total
-                    );
-                }
-            }
-            QueryType::KeywordList(keywords_list) => {
-                for (idx, keywords) in keywords_list.into_iter().enumerate() {
-                    let queue =
-                        insert_track(&call, &QueryType::Keywords(keywords), idx + 1).await?;
-                    update_queue_messages(&ctx.http, &ctx.data, &queue, guild_id).await;
-                }
-            }
-        },
-        Mode::Jump => match query_type.clone() {
-            QueryType::Keywords(_) | QueryType::VideoLink(_) => {
-                let mut queue = enqueue_track(&call, &query_type).await?;
-
-                if !queue_was_empty {
-                    rotate_tracks(&call, 1).await.ok();
-                    queue = force_skip_top_track(&call.lock().await).await?;
-                }
+    let report = match (mode, query_type.clone()) {
+        (Mode::End, QueryType::Keywords(_) | QueryType::VideoLink(_)) => {
+            let queue =
+                enqueue_track(&call, &query_type, Some(interaction.user.id), &filter).await?;
+            update_queue_messages(&ctx.http, &ctx.data, &queue, guild_id).await;
+            None
+        }
+        (Mode::Next, QueryType::Keywords(_) | QueryType::VideoLink(_)) => {
+            let queue =
+                insert_track(&call, &query_type, 1, Some(interaction.user.id), &filter).await?;
+            update_queue_messages(&ctx.http, &ctx.data, &queue, guild_id).await;
+            None
+        }
+        (Mode::Jump, QueryType::Keywords(_) | QueryType::VideoLink(_)) => {
+            let mut queue =
+                enqueue_track(&call, &query_type, Some(interaction.user.id), &filter).await?;
 
-                update_queue_messages(&ctx.http, &ctx.data, &queue, guild_id).await;
+            if !queue_was_empty {
+                rotate_tracks(&call, 1).await.ok();
+                queue = force_skip_top_track(&call.lock().await).await?;
             }
-            QueryType::PlaylistLink(url) => {
-                let urls = YouTubeRestartable::ytdl_playlist(&url, mode)
-                    .await
-                    .ok_or(ParrotError::Other("failed to fetch playlist"))?;
-
-                let total = urls.len();
-                let mut insert_idx = 1;
-                let mut failed_count = 0;
-
-                for (i, url) in urls.into_iter().enumerate() {
-                    match insert_track(&call, &QueryType::VideoLink(url.clone()), insert_idx).await
-                    {
-                        Ok(mut queue) => {
-                            if i == 0 && !queue_was_empty {
-                                queue = force_skip_top_track(&call.lock().await).await?;
-                            } else {
-                                insert_idx += 1;
-                            }
-                            update_queue_messages(&ctx.http, &ctx.data, &queue, guild_id).await;
-                        }
-                        Err(e) => {
-                            eprintln!("[WARN] Failed to insert track {}: {}", url, e);
-                            failed_count += 1;
-                        }
-                    }
-                }
-                if failed_count > 0 {
-                    eprintln!(
-                        "[WARN] Playlist (jump): {}/{} tracks failed to insert",
-                        failed_count, total
-                    );
-                }
-            }
-            QueryType::KeywordList(keywords_list) => {
-                let mut insert_idx = 1;
-
-                for (i, keywords) in keywords_list.into_iter().enumerate() {
-                    let mut queue =
-                        insert_track(&call, &QueryType::Keywords(keywords), insert_idx).await?;
 
-                    if i == 0 && !queue_was_empty {
-                        queue = force_skip_top_track(&call.lock().await).await?;
-                    } else {
-                        insert_idx += 1;
-                    }
+            update_queue_messages(&ctx.http, &ctx.data, &queue, guild_id).await;
+            None
+        }
+        (Mode::All | Mode::Reverse | Mode::Shuffle, QueryType::Keywords(_)) => {
+            edit_response(&ctx.http, interaction, ParrotMessage::PlayAllFailed).await?;
+            return Ok(());
+        }
+        (_, QueryType::VideoLink(url) | QueryType::PlaylistLink(url)) => {
+            let urls = YouTubeRestartable::ytdl_playlist(&url, mode, range)
+                .await
+                .ok_or(ParrotError::Other(FAIL_FETCH_PLAYLIST))?;
 
-                    update_queue_messages(&ctx.http, &ctx.data, &queue, guild_id).await;
-                }
-            }
-        },
-        Mode::All | Mode::Reverse | Mode::Shuffle => match query_type.clone() {
-            QueryType::VideoLink(url) | QueryType::PlaylistLink(url) => {
-                let urls = YouTubeRestartable::ytdl_playlist(&url, mode)
-                    .await
-                    .ok_or(ParrotError::Other("failed to fetch playlist"))?;
-
-                let total = urls.len();
-                let mut failed_count = 0;
-                for url in urls.into_iter() {
-                    match enqueue_track(&call, &QueryType::VideoLink(url.clone())).await {
-                        Ok(queue) => {
-                            update_queue_messages(&ctx.http, &ctx.data, &queue, guild_id).await;
-                        }
-                        Err(e) => {
-                            eprintln!("[WARN] Failed to enqueue track {}: {}", url, e);
-                            failed_count += 1;
-                        }
-                    }
-                }
-                if failed_count > 0 {
-                    eprintln!(
-                        "[WARN] Playlist: {}/{} tracks failed to enqueue",
-                        failed_count, total
-                    );
-                }
-            }
-            QueryType::KeywordList(keywords_list) => {
-                for keywords in keywords_list.into_iter() {
-                    let queue = enqueue_track(&call, &QueryType::Keywords(keywords)).await?;
-                    update_queue_messages(&ctx.http, &ctx.data, &queue, guild_id).await;
-                }
-            }
-            _ => {
-                edit_response(&ctx.http, interaction, ParrotMessage::PlayAllFailed).await?;
-                return Ok(());
-            }
-        },
-    }
+            let queries = urls.into_iter().map(QueryType::VideoLink).collect();
+            Some(
+                import_playlist(
+                    ctx,
+                    interaction,
+                    &call,
+                    queries,
+                    mode,
+                    queue_was_empty,
+                    &filter,
+                )
+                .await?,
+            )
+        }
+        (_, QueryType::KeywordList(keywords_list)) => {
+            let queries = keywords_list.into_iter().map(QueryType::Keywords).collect();
+            Some(
+                import_playlist(
+                    ctx,
+                    interaction,
+                    &call,
+                    queries,
+                    mode,
+                    queue_was_empty,
+                    &filter,
+                )
+                .await?,
+            )
+        }
+    };
 
     let handler = call.lock().await;
 
@@ -375,6 +345,19 @@ pub async fn play(ctx: &Context, interaction: &mut CommandInteraction) -> Result
     let queue = handler.queue().current_queue();
     drop(handler);
 
+    if let Some(report) = report {
+        // a playlist that ended up as the only track is better shown as now playing
+        if queue.len() != 1 || report.cancelled || !report.failures.is_empty() {
+            edit_response(
+                &ctx.http,
+                interaction,
+                ParrotMessage::PlaylistQueued { report },
+            )
+            .await?;
+            return Ok(());
+        }
+    }
+
     match queue.len().cmp(&1) {
         Ordering::Greater => {
             let estimated_time = calculate_time_until_play(&queue, mode)
@@ -384,19 +367,17 @@ pub async fn play(ctx: &Context, interaction: &mut CommandInteraction) -> Result
             match (query_type, mode) {
                 (QueryType::VideoLink(_) | QueryType::Keywords(_), Mode::Next) => {
                     if let Some(track) = queue.get(1) {
-                        let embed = create_queued_embed(PLAY_TOP, track, estimated_time).await;
+                        let embed = create_queued_embed(tr(PLAY_TOP), track, estimated_time).await;
                         edit_embed_response(&ctx.http, interaction, embed).await?;
                     }
                 }
                 (QueryType::VideoLink(_) | QueryType::Keywords(_), Mode::End) => {
                     if let Some(track) = queue.last() {
-                        let embed = create_queued_embed(PLAY_QUEUE, track, estimated_time).await;
+                        let embed =
+                            create_queued_embed(tr(PLAY_QUEUE), track, estimated_time).await;
                         edit_embed_response(&ctx.http, interaction, embed).await?;
                     }
                 }
-                (QueryType::PlaylistLink(_) | QueryType::KeywordList(_), _) => {
-                    edit_response(&ctx.http, interaction, ParrotMessage::PlaylistQueued).await?;
-                }
                 (_, _) => {}
             }
         }
@@ -461,9 +442,9 @@ async fn create_queued_embed(
 
     let footer_text = format!(
         "{}{}\n{}{}",
-        TRACK_DURATION,
+        tr(TRACK_DURATION),
         get_human_readable_timestamp(metadata.duration),
-        TRACK_TIME_TO_PLAY,
+        tr(TRACK_TIME_TO_PLAY),
         get_human_readable_timestamp(Some(estimated_time))
     );
 
@@ -484,9 +465,7 @@ async fn create_queued_embed(
     embed.footer(CreateEmbedFooter::new(footer_text))
 }
 
-async fn get_track_source(
-    query_type: QueryType,
-) -> Result<(songbird::input::Input, AuxMetadata), ParrotError> {
+async fn get_track_source(query_type: QueryType) -> Result<ResolvedTrack, ParrotError> {
     match query_type {
         QueryType::VideoLink(query) => YouTubeRestartable::ytdl(query).await,
         QueryType::Keywords(query) => YouTubeRestartable::ytdl_search(query).await,
@@ -494,35 +473,72 @@ async fn get_track_source(
     }
 }
 
+/// Appends every entry of a playlist to the queue without anyone having requested it,
+/// skipping entries that fail to resolve, and returns how many were queued.
+pub async fn enqueue_playlist(
+    http: &Arc<Http>,
+    data: &Arc<RwLock<TypeMap>>,
+    call: &Arc<Mutex<Call>>,
+    guild_id: GuildId,
+    url: &str,
+    filter: &ContentFilter,
+) -> usize {
+    let urls = YouTubeRestartable::ytdl_playlist(url, Mode::End, PlaylistRange::default())
+        .await
+        .unwrap_or_default();
+
+    let loader = PlaylistLoader {
+        http,
+        data,
+        call,
+        guild_id,
+        mode: Mode::End,
+        queue_was_empty: call.lock().await.queue().is_empty(),
+        requester: None,
+        filter: filter.clone(),
+        token: CancellationToken::new(),
+    };
+
+    let queries = urls.into_iter().map(QueryType::VideoLink).collect();
+    loader
+        .load(None, queries)
+        .await
+        .map(|report| report.queued)
+        .unwrap_or_default()
+}
+
 async fn enqueue_track(
     call: &Arc<Mutex<Call>>,
     query_type: &QueryType,
+    requester: Option<UserId>,
+    filter: &ContentFilter,
 ) -> Result<Vec<TrackHandle>, ParrotError> {
-    use songbird::tracks::Track;
-
     // safeguard against ytdl dying on a private/deleted video and killing the playlist
-    let (source, metadata) = get_track_source(query_type.clone()).await?;
-
-    // Create a track with the metadata stored as user data
-    let track = Track::new_with_data(source, Arc::new(metadata));
-
+    let track = get_track_source(query_type.clone()).await?;
+    filter
+        .check(&track.metadata, track.age_limit)
+        .map_err(ParrotError::TrackFiltered)?;
+    let data = TrackData {
+        metadata: track.metadata,
+        requester,
+    };
     let mut handler = call.lock().await;
-    handler.enqueue(track).await;
-
-    Ok(handler.queue().current_queue())
+    Ok(enqueue_resolved(&mut handler, track.source, data).await)
 }
 
 async fn insert_track(
     call: &Arc<Mutex<Call>>,
     query_type: &QueryType,
     idx: usize,
+    requester: Option<UserId>,
+    filter: &ContentFilter,
 ) -> Result<Vec<TrackHandle>, ParrotError> {
     let handler = call.lock().await;
     let queue_size = handler.queue().len();
     drop(handler);
 
     if queue_size <= 1 {
-        let queue = enqueue_track(call, query_type).await?;
+        let queue = enqueue_track(call, query_type, requester, filter).await?;
         return Ok(queue);
     }
 
@@ -531,15 +547,362 @@ async fn insert_track(
         ParrotError::NotInRange("index", idx as isize, 1, queue_size as isize),
     )?;
 
-    enqueue_track(call, query_type).await?;
+    let track = get_track_source(query_type.clone()).await?;
+    filter
+        .check(&track.metadata, track.age_limit)
+        .map_err(ParrotError::TrackFiltered)?;
 
-    let handler = call.lock().await;
+    let data = TrackData {
+        metadata: track.metadata,
+        requester,
+    };
+    let mut handler = call.lock().await;
+    Ok(insert_resolved(&mut handler, track.source, data, idx).await)
+}
+
+async fn enqueue_resolved(handler: &mut Call, source: Input, data: TrackData) -> Vec<TrackHandle> {
+    // create a track with the metadata stored as user data
+    let track = Track::new_with_data(source, Arc::new(data));
+
+    handler.enqueue(track).await;
+
+    handler.queue().current_queue()
+}
+
+async fn insert_resolved(
+    handler: &mut Call,
+    source: Input,
+    data: TrackData,
+    idx: usize,
+) -> Vec<TrackHandle> {
+    let track = Track::new_with_data(source, Arc::new(data));
+
+    handler.enqueue(track).await;
+
+    // the track currently playing is never displaced and indexes past the end simply append
     handler.queue().modify_queue(|queue| {
-        let back = queue.pop_back().unwrap();
-        queue.insert(idx, back);
+        if idx > 0 && idx < queue.len() - 1 {
+            let back = queue.pop_back().unwrap();
+            queue.insert(idx, back);
+        }
     });
 
-    Ok(handler.queue().current_queue())
+    handler.queue().current_queue()
+}
+
+/// A playlist entry that couldn't be queued and why.
+#[derive(Debug)]
+pub struct PlaylistFailure {
+    pub entry: String,
+    pub reason: UnavailableReason,
+}
+
+/// Summary of a playlist import, shown to the user once it finishes.
+#[derive(Debug)]
+pub struct PlaylistReport {
+    pub queued: usize,
+    pub total: usize,
+    pub failures: Vec<PlaylistFailure>,
+    pub cancelled: bool,
+}
+
+/// Queues the resolved entries of a playlist while tracking the import for the guild, so
+/// that it can be cancelled with `/cancel`, the progress message's button or commands
+/// that throw the queue away.
+async fn import_playlist(
+    ctx: &Context,
+    interaction: &mut CommandInteraction,
+    call: &Arc<Mutex<Call>>,
+    queries: Vec<QueryType>,
+    mode: Mode,
+    queue_was_empty: bool,
+    filter: &ContentFilter,
+) -> Result<PlaylistReport, ParrotError> {
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
+
+    let total = queries.len();
+    let threshold = *PLAYLIST_CONFIRM_THRESHOLD;
+
+    if threshold > 0 && total > threshold && !confirm_import(ctx, interaction, total).await? {
+        // the buttons would otherwise stay clickable on the declined or expired prompt
+        let edit = EditInteractionResponse::new().components(vec![]);
+        interaction.edit_response(&ctx.http, edit).await.ok();
+
+        return Ok(PlaylistReport {
+            queued: 0,
+            total,
+            failures: vec![],
+            cancelled: true,
+        });
+    }
+
+    let loader = PlaylistLoader {
+        http: &ctx.http,
+        data: &ctx.data,
+        call,
+        guild_id,
+        mode,
+        queue_was_empty,
+        requester: Some(interaction.user.id),
+        filter: filter.clone(),
+        token: register_import(&ctx.data, guild_id, interaction.id).await,
+    };
+
+    loader.edit_progress(interaction, 0, total).await;
+
+    let message = interaction.get_response(&ctx.http).await?;
+    let watcher = watch_cancel_button(ctx, message, interaction.user.id, loader.token.clone());
+    let report = loader.load(Some(interaction), queries).await;
+    watcher.abort();
+
+    forget_import(&ctx.data, guild_id, interaction.id)
+        .await
+        .ok();
+
+    // the cancel button is meaningless once the import is over
+    let edit = EditInteractionResponse::new().components(vec![]);
+    interaction.edit_response(&ctx.http, edit).await.ok();
+
+    report
+}
+
+/// Asks the requester whether a large playlist should really be imported.
+/// Not answering in time counts as declining.
+async fn confirm_import(
+    ctx: &Context,
+    interaction: &CommandInteraction,
+    total: usize,
+) -> Result<bool, ParrotError> {
+    let confirm = CreateButton::new(CONFIRM_IMPORT_BUTTON_ID)
+        .label(tr(PLAYLIST_CONFIRM_BUTTON))
+        .style(ButtonStyle::Success);
+    let cancel = CreateButton::new(CANCEL_IMPORT_BUTTON_ID)
+        .label(tr(PLAYLIST_CANCEL_BUTTON))
+        .style(ButtonStyle::Danger);
+
+    let prompt = ParrotMessage::PlaylistConfirm { total };
+    let edit = EditInteractionResponse::new()
+        .content(" ")
+        .embed(CreateEmbed::new().description(format!("{prompt}")))
+        .components(vec![CreateActionRow::Buttons(vec![confirm, cancel])]);
+
+    let message = interaction.edit_response(&ctx.http, edit).await?;
+
+    let answer = message
+        .await_component_interaction(ctx)
+        .author_id(interaction.user.id)
+        .timeout(Duration::from_secs(CONFIRM_IMPORT_TIMEOUT))
+        .await;
+
+    let Some(mci) = answer else {
+        return Ok(false);
+    };
+
+    mci.create_response(&ctx.http, CreateInteractionResponse::Acknowledge)
+        .await
+        .ok();
+
+    Ok(mci.data.custom_id == CONFIRM_IMPORT_BUTTON_ID)
+}
+
+/// Resolves a list of playlist entries with bounded concurrency and queues them in their
+/// original order according to `mode`. Entries that fail to resolve are skipped, the
+/// interaction response, if any, is periodically edited with the import's progress and the queue
+/// messages are refreshed in debounced batches rather than once per track.
+/// The import can be aborted through its cancellation token at any point.
+struct PlaylistLoader<'a> {
+    http: &'a Arc<Http>,
+    data: &'a Arc<RwLock<TypeMap>>,
+    call: &'a Arc<Mutex<Call>>,
+    guild_id: GuildId,
+    mode: Mode,
+    queue_was_empty: bool,
+    requester: Option<UserId>,
+    filter: ContentFilter,
+    token: CancellationToken,
+}
+
+impl PlaylistLoader<'_> {
+    async fn load(
+        &self,
+        interaction: Option<&CommandInteraction>,
+        queries: Vec<QueryType>,
+    ) -> Result<PlaylistReport, ParrotError> {
+        let total = queries.len();
+
+        // each entry is resolved on its own task so lookups keep progressing while we enqueue,
+        // `buffered` caps how many are in flight and yields them back in submission order
+        let mut resolved = stream::iter(queries)
+            .map(|query| {
+                let entry = match &query {
+                    QueryType::Keywords(entry) | QueryType::VideoLink(entry) => entry.clone(),
+                    _ => String::new(),
+                };
+                // aborted if the import is cancelled or fails before the entry is consumed
+                let task = AbortOnDropHandle::new(tokio::spawn(get_track_source(query)));
+                async move { (entry, task.await) }
+            })
+            .buffered(PLAYLIST_CONCURRENCY);
+
+        let mut queue_debouncer = Debouncer::new(QUEUE_REFRESH_INTERVAL);
+        let mut progress_debouncer = Debouncer::new(PROGRESS_REFRESH_INTERVAL);
+        let mut failures = vec![];
+        let mut queued = 0;
+        let mut queue = vec![];
+
+        loop {
+            let next = tokio::select! {
+                biased;
+                _ = self.token.cancelled() => break,
+                next = resolved.next() => next,
+            };
+
+            let Some((entry, result)) = next else {
+                break;
+            };
+
+            match result {
+                Ok(Ok(track)) => {
+                    if let Err(rule) = self.filter.check(&track.metadata, track.age_limit) {
+                        let reason = UnavailableReason::Filtered(rule);
+                        failures.push(PlaylistFailure { entry, reason });
+                        continue;
+                    }
+
+                    let Some(placed) = self.place(track.source, track.metadata, queued).await?
+                    else {
+                        break;
+                    };
+                    queue = placed;
+                    queued += 1;
+
+                    if queue_debouncer.ready() {
+                        update_queue_messages(self.http, self.data, &queue, self.guild_id).await;
+                    }
+                }
+                Ok(Err(err)) => {
+                    eprintln!("[WARN] Failed to resolve playlist entry {}: {}", entry, err);
+                    let reason = UnavailableReason::from_error(&err.to_string());
+                    failures.push(PlaylistFailure { entry, reason });
+                }
+                Err(err) => {
+                    eprintln!("[WARN] Playlist entry task for {} failed: {}", entry, err);
+                    let reason = UnavailableReason::Other;
+                    failures.push(PlaylistFailure { entry, reason });
+                }
+            }
+
+            let resolved = queued + failures.len();
+            if let Some(interaction) = interaction.filter(|_| resolved < total) {
+                if progress_debouncer.ready() {
+                    self.edit_progress(interaction, resolved, total).await;
+                }
+            }
+        }
+
+        // stops the entries that are still being resolved
+        drop(resolved);
+
+        // flush whatever the debouncer held back
+        update_queue_messages(self.http, self.data, &queue, self.guild_id).await;
+
+        if !failures.is_empty() {
+            eprintln!(
+                "[WARN] Playlist: {}/{} tracks failed to enqueue",
+                failures.len(),
+                total
+            );
+        }
+
+        Ok(PlaylistReport {
+            queued,
+            total,
+            failures,
+            cancelled: self.token.is_cancelled(),
+        })
+    }
+
+    async fn edit_progress(&self, interaction: &CommandInteraction, resolved: usize, total: usize) {
+        let progress = ParrotMessage::PlaylistProgress { resolved, total };
+        let cancel = CreateButton::new(CANCEL_IMPORT_BUTTON_ID)
+            .label(tr(PLAYLIST_CANCEL_BUTTON))
+            .style(ButtonStyle::Danger);
+
+        let edit = EditInteractionResponse::new()
+            .content(" ")
+            .embed(CreateEmbed::new().description(format!("{progress}")))
+            .components(vec![CreateActionRow::Buttons(vec![cancel])]);
+
+        if let Err(err) = interaction.edit_response(self.http, edit).await {
+            eprintln!("[WARN] Failed to update playlist progress: {}", err);
+        }
+    }
+
+    /// Places the `queued`-th resolved track of the playlist in the queue, unless the import
+    /// has been cancelled in the meantime.
+    async fn place(
+        &self,
+        source: Input,
+        metadata: AuxMetadata,
+        queued: usize,
+    ) -> Result<Option<Vec<TrackHandle>>, ParrotError> {
+        let data = TrackData {
+            metadata,
+            requester: self.requester,
+        };
+
+        // imports are cancelled before the queue is thrown away, so checking while holding
+        // the call guarantees a track never ends up in a queue that was just stopped or cleared
+        let mut handler = self.call.lock().await;
+        if self.token.is_cancelled() {
+            return Ok(None);
+        }
+
+        let queue = match self.mode {
+            Mode::Next => insert_resolved(&mut handler, source, data, queued + 1).await,
+            Mode::Jump => {
+                // the first track jumps the queue, the rest are inserted right after it
+                let skipped = !self.queue_was_empty && queued > 0;
+                let idx = if skipped { queued } else { queued + 1 };
+                let queue = insert_resolved(&mut handler, source, data, idx).await;
+
+                if !self.queue_was_empty && queued == 0 {
+                    force_skip_top_track(&handler).await?
+                } else {
+                    queue
+                }
+            }
+            _ => enqueue_resolved(&mut handler, source, data).await,
+        };
+
+        Ok(Some(queue))
+    }
+}
+
+/// Cancels an import when its requester presses the button on the progress message.
+fn watch_cancel_button(
+    ctx: &Context,
+    message: Message,
+    user_id: UserId,
+    token: CancellationToken,
+) -> JoinHandle<()> {
+    let ctx = ctx.clone();
+
+    tokio::spawn(async move {
+        let collector = message
+            .await_component_interaction(&ctx)
+            .author_id(user_id)
+            .custom_ids(vec![CANCEL_IMPORT_BUTTON_ID.to_string()]);
+
+        if let Some(mci) = collector.await {
+            token.cancel();
+            mci.create_response(&ctx.http, CreateInteractionResponse::Acknowledge)
+                .await
+                .ok();
+        }
+    })
 }
 
 async fn rotate_tracks(
@@ -550,7 +913,7 @@ async fn rotate_tracks(
 
     verify(
         handler.queue().len() > 2,
-        ParrotError::Other("cannot rotate queues smaller than 3 tracks"),
+        ParrotError::Other(FAIL_ROTATE_QUEUE),
     )?;
 
     handler.queue().modify_queue(|queue| {
@@ -3,9 +3,10 @@ use crate::{
     errors::ParrotError,
     guild::cache::GuildCacheMap,
     handlers::track_end::ModifyQueueHandler,
+    messaging::i18n::tr,
     messaging::messages::{
-        QUEUE_EXPIRED, QUEUE_NOTHING_IS_PLAYING, QUEUE_NOW_PLAYING, QUEUE_NO_SONGS, QUEUE_PAGE,
-        QUEUE_PAGE_OF, QUEUE_UP_NEXT,
+        FAIL_GUILD_ONLY, FAIL_VOICE_MANAGER, QUEUE_EXPIRED, QUEUE_NOTHING_IS_PLAYING,
+        QUEUE_NOW_PLAYING, QUEUE_NO_SONGS, QUEUE_PAGE, QUEUE_PAGE_OF, QUEUE_UP_NEXT,
     },
     utils::get_human_readable_timestamp,
 };
@@ -35,13 +36,13 @@ const EMBED_TIMEOUT: u64 = 3600;
 pub async fn queue(ctx: &Context, interaction: &mut CommandInteraction) -> Result<(), ParrotError> {
     use serenity::all::EditMessage;
 
-    let guild_id = interaction.guild_id.ok_or(ParrotError::Other(
-        "This command can only be used in a server",
-    ))?;
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
 
     let manager = songbird::get(ctx)
         .await
-        .ok_or(ParrotError::Other("Voice manager not configured"))?;
+        .ok_or(ParrotError::Other(FAIL_VOICE_MANAGER))?;
 
     let call = manager.get(guild_id).ok_or(ParrotError::NotConnected)?;
 
@@ -115,7 +116,7 @@ pub async fn queue(ctx: &Context, interaction: &mut CommandInteraction) -> Resul
     }
 
     let edit = EditMessage::new()
-        .embed(CreateEmbed::new().description(QUEUE_EXPIRED))
+        .embed(CreateEmbed::new().description(tr(QUEUE_EXPIRED)))
         .components(vec![]);
     if let Err(e) = message.edit(&ctx.http, edit).await {
         eprintln!("[WARN] Failed to edit queue message: {}", e);
@@ -139,20 +140,20 @@ pub fn create_queue_embed(tracks: &[TrackHandle], page: usize) -> CreateEmbed {
         );
         (desc, metadata.thumbnail)
     } else {
-        (String::from(QUEUE_NOTHING_IS_PLAYING), None)
+        (String::from(tr(QUEUE_NOTHING_IS_PLAYING)), None)
     };
 
     let footer_text = format!(
         "{} {} {} {}",
-        QUEUE_PAGE,
+        tr(QUEUE_PAGE),
         page + 1,
-        QUEUE_PAGE_OF,
+        tr(QUEUE_PAGE_OF),
         calculate_num_pages(tracks),
     );
 
     let mut embed = CreateEmbed::new()
-        .field(QUEUE_NOW_PLAYING, &description, false)
-        .field(QUEUE_UP_NEXT, build_queue_page(tracks, page), false)
+        .field(tr(QUEUE_NOW_PLAYING), &description, false)
+        .field(tr(QUEUE_UP_NEXT), build_queue_page(tracks, page), false)
         .footer(CreateEmbedFooter::new(footer_text));
 
     if let Some(thumb) = thumbnail {
@@ -189,7 +190,7 @@ fn build_queue_page(tracks: &[TrackHandle], page: usize) -> String {
         .collect();
 
     if queue.is_empty() {
-        return String::from(QUEUE_NO_SONGS);
+        return String::from(tr(QUEUE_NO_SONGS));
     }
 
     let mut description = String::new();
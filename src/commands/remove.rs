@@ -2,8 +2,9 @@ use crate::{
     commands::play::get_track_metadata,
     errors::{verify, ParrotError},
     handlers::track_end::update_queue_messages,
+    messaging::i18n::tr,
     messaging::message::ParrotMessage,
-    messaging::messages::REMOVED_QUEUE,
+    messaging::messages::{FAIL_GUILD_ONLY, FAIL_VOICE_MANAGER, REMOVED_QUEUE},
     utils::create_embed_response,
     utils::create_response,
 };
@@ -15,13 +16,13 @@ pub async fn remove(
     ctx: &Context,
     interaction: &mut CommandInteraction,
 ) -> Result<(), ParrotError> {
-    let guild_id = interaction.guild_id.ok_or(ParrotError::Other(
-        "This command can only be used in a server",
-    ))?;
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
 
     let manager = songbird::get(ctx)
         .await
-        .ok_or(ParrotError::Other("Voice manager not configured"))?;
+        .ok_or(ParrotError::Other(FAIL_VOICE_MANAGER))?;
 
     let call = manager.get(guild_id).ok_or(ParrotError::NotConnected)?;
 
@@ -80,7 +81,7 @@ async fn create_remove_enqueued_embed(track: &TrackHandle) -> CreateEmbed {
     let metadata = get_track_metadata(track).unwrap_or_default();
 
     let mut embed = CreateEmbed::new().field(
-        REMOVED_QUEUE,
+        tr(REMOVED_QUEUE),
         format!(
             "[**{}**]({})",
             metadata.title.unwrap_or_default(),
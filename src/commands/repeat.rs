@@ -1,5 +1,7 @@
 use crate::{
-    errors::ParrotError, messaging::message::ParrotMessage, messaging::messages::FAIL_LOOP,
+    errors::ParrotError,
+    messaging::message::ParrotMessage,
+    messaging::messages::{FAIL_GUILD_ONLY, FAIL_LOOP, FAIL_TRACK_INFO, FAIL_VOICE_MANAGER},
     utils::create_response,
 };
 use serenity::{all::CommandInteraction, client::Context};
@@ -9,12 +11,12 @@ pub async fn repeat(
     ctx: &Context,
     interaction: &mut CommandInteraction,
 ) -> Result<(), ParrotError> {
-    let guild_id = interaction.guild_id.ok_or(ParrotError::Other(
-        "This command can only be used in a server",
-    ))?;
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
     let manager = songbird::get(ctx)
         .await
-        .ok_or(ParrotError::Other("Voice manager not configured"))?;
+        .ok_or(ParrotError::Other(FAIL_VOICE_MANAGER))?;
     let call = manager.get(guild_id).ok_or(ParrotError::NotConnected)?;
 
     let handler = call.lock().await;
@@ -26,7 +28,7 @@ pub async fn repeat(
     let track_info = track
         .get_info()
         .await
-        .map_err(|_| ParrotError::Other("Failed to get track info"))?;
+        .map_err(|_| ParrotError::Other(FAIL_TRACK_INFO))?;
     let was_looping = track_info.loops == LoopState::Infinite;
     let toggler = if was_looping {
         TrackHandle::disable_loop
@@ -1,6 +1,8 @@
 use crate::{
     errors::{verify, ParrotError},
+    handlers::voice_state::forget_auto_pause,
     messaging::message::ParrotMessage,
+    messaging::messages::{FAIL_GUILD_ONLY, FAIL_RESUME, FAIL_VOICE_MANAGER},
     utils::create_response,
 };
 use serenity::{all::CommandInteraction, client::Context};
@@ -9,19 +11,22 @@ pub async fn resume(
     ctx: &Context,
     interaction: &mut CommandInteraction,
 ) -> Result<(), ParrotError> {
-    let guild_id = interaction.guild_id.ok_or(ParrotError::Other(
-        "This command can only be used in a server",
-    ))?;
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
     let manager = songbird::get(ctx)
         .await
-        .ok_or(ParrotError::Other("Voice manager not configured"))?;
+        .ok_or(ParrotError::Other(FAIL_VOICE_MANAGER))?;
     let call = manager.get(guild_id).ok_or(ParrotError::NotConnected)?;
 
+    // resumed by hand, so the track is no longer the bot's to resume once listeners return
+    forget_auto_pause(&ctx.data, guild_id).await;
+
     let handler = call.lock().await;
     let queue = handler.queue();
 
     verify(!queue.is_empty(), ParrotError::NothingPlaying)?;
-    verify(queue.resume(), ParrotError::Other("Failed resuming track"))?;
+    verify(queue.resume(), ParrotError::Other(FAIL_RESUME))?;
 
     create_response(&ctx.http, interaction, ParrotMessage::Resume).await
 }
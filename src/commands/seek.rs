@@ -1,19 +1,21 @@
 use crate::{
     errors::{verify, ParrotError},
     messaging::message::ParrotMessage,
-    messaging::messages::{FAIL_MINUTES_PARSING, FAIL_SECONDS_PARSING},
+    messaging::messages::{
+        FAIL_GUILD_ONLY, FAIL_MINUTES_PARSING, FAIL_SECONDS_PARSING, FAIL_VOICE_MANAGER,
+    },
     utils::create_response,
 };
 use serenity::{all::CommandInteraction, client::Context};
 use std::time::Duration;
 
 pub async fn seek(ctx: &Context, interaction: &mut CommandInteraction) -> Result<(), ParrotError> {
-    let guild_id = interaction.guild_id.ok_or(ParrotError::Other(
-        "This command can only be used in a server",
-    ))?;
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
     let manager = songbird::get(ctx)
         .await
-        .ok_or(ParrotError::Other("Voice manager not configured"))?;
+        .ok_or(ParrotError::Other(FAIL_VOICE_MANAGER))?;
     let call = manager.get(guild_id).ok_or(ParrotError::NotConnected)?;
 
     let args = interaction.data.options.clone();
@@ -1,33 +1,29 @@
 use crate::{
-    errors::ParrotError, handlers::track_end::update_queue_messages,
-    messaging::message::ParrotMessage, utils::create_response,
+    errors::ParrotError,
+    handlers::track_end::update_queue_messages,
+    messaging::message::ParrotMessage,
+    messaging::messages::{FAIL_GUILD_ONLY, FAIL_VOICE_MANAGER},
+    utils::create_response,
 };
 use rand::Rng;
 use serenity::{all::CommandInteraction, client::Context};
+use songbird::tracks::TrackQueue;
 
 pub async fn shuffle(
     ctx: &Context,
     interaction: &mut CommandInteraction,
 ) -> Result<(), ParrotError> {
-    let guild_id = interaction.guild_id.ok_or(ParrotError::Other(
-        "This command can only be used in a server",
-    ))?;
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
     let manager = songbird::get(ctx)
         .await
-        .ok_or(ParrotError::Other("Voice manager not configured"))?;
+        .ok_or(ParrotError::Other(FAIL_VOICE_MANAGER))?;
     let call = manager.get(guild_id).ok_or(ParrotError::NotConnected)?;
 
     let handler = call.lock().await;
 
-    if !handler.queue().is_empty() {
-        handler.queue().modify_queue(|queue| {
-            // skip the first track on queue because it's being played
-            fisher_yates(
-                queue.make_contiguous()[1..].as_mut(),
-                &mut rand::thread_rng(),
-            )
-        });
-    }
+    shuffle_upcoming(handler.queue());
 
     // refetch the queue after modification
     let queue = handler.queue().current_queue();
@@ -38,6 +34,21 @@ pub async fn shuffle(
     Ok(())
 }
 
+/// Shuffles every track in the queue except the one that's playing.
+pub fn shuffle_upcoming(queue: &TrackQueue) {
+    if queue.is_empty() {
+        return;
+    }
+
+    queue.modify_queue(|queue| {
+        // skip the first track on queue because it's being played
+        fisher_yates(
+            queue.make_contiguous()[1..].as_mut(),
+            &mut rand::thread_rng(),
+        )
+    });
+}
+
 fn fisher_yates<T, R>(values: &mut [T], mut rng: R)
 where
     R: rand::RngCore + Sized,
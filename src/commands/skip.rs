@@ -2,6 +2,7 @@ use crate::{
     commands::play::get_track_metadata,
     errors::{verify, ParrotError},
     messaging::message::ParrotMessage,
+    messaging::messages::{FAIL_GUILD_ONLY, FAIL_VOICE_MANAGER},
     utils::create_response,
 };
 use serenity::{all::CommandInteraction, client::Context};
@@ -10,13 +11,13 @@ use std::cmp::min;
 use tokio::sync::MutexGuard;
 
 pub async fn skip(ctx: &Context, interaction: &mut CommandInteraction) -> Result<(), ParrotError> {
-    let guild_id = interaction.guild_id.ok_or(ParrotError::Other(
-        "This command can only be used in a server",
-    ))?;
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
 
     let manager = songbird::get(ctx)
         .await
-        .ok_or(ParrotError::Other("Voice manager not configured"))?;
+        .ok_or(ParrotError::Other(FAIL_VOICE_MANAGER))?;
 
     let call = manager.get(guild_id).ok_or(ParrotError::NotConnected)?;
 
@@ -47,26 +48,21 @@ pub async fn create_skip_response(
     handler: &MutexGuard<'_, Call>,
     tracks_to_skip: usize,
 ) -> Result<(), ParrotError> {
+    let message = create_skip_message(handler, tracks_to_skip);
+    create_response(&ctx.http, interaction, message).await
+}
+
+pub fn create_skip_message(handler: &MutexGuard<'_, Call>, tracks_to_skip: usize) -> ParrotMessage {
     match handler.queue().current() {
         Some(track) => {
             let metadata = get_track_metadata(&track).unwrap_or_default();
-            create_response(
-                &ctx.http,
-                interaction,
-                ParrotMessage::SkipTo {
-                    title: metadata.title.unwrap_or_else(|| "Unknown".to_string()),
-                    url: metadata.source_url.unwrap_or_else(|| "#".to_string()),
-                },
-            )
-            .await
-        }
-        None => {
-            if tracks_to_skip > 1 {
-                create_response(&ctx.http, interaction, ParrotMessage::SkipAll).await
-            } else {
-                create_response(&ctx.http, interaction, ParrotMessage::Skip).await
+            ParrotMessage::SkipTo {
+                title: metadata.title.unwrap_or_else(|| "Unknown".to_string()),
+                url: metadata.source_url.unwrap_or_else(|| "#".to_string()),
             }
         }
+        None if tracks_to_skip > 1 => ParrotMessage::SkipAll,
+        None => ParrotMessage::Skip,
     }
 }
 
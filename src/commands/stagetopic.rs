@@ -0,0 +1,35 @@
+use crate::{
+    errors::ParrotError,
+    guild::settings::{GuildSettings, GuildSettingsMap},
+    messaging::message::ParrotMessage,
+    messaging::messages::FAIL_GUILD_ONLY,
+    utils::create_response,
+};
+use serenity::{all::CommandInteraction, client::Context};
+
+pub async fn stagetopic(
+    ctx: &Context,
+    interaction: &mut CommandInteraction,
+) -> Result<(), ParrotError> {
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
+
+    let mut data = ctx.data.write().await;
+    let settings = data.get_mut::<GuildSettingsMap>().unwrap();
+
+    let guild_settings = settings
+        .entry(guild_id)
+        .or_insert_with(|| GuildSettings::new(guild_id));
+    guild_settings.toggle_stage_topic();
+    guild_settings.save()?;
+
+    let enabled = guild_settings.stage_topic;
+    drop(data);
+
+    if enabled {
+        create_response(&ctx.http, interaction, ParrotMessage::StageTopicOn).await
+    } else {
+        create_response(&ctx.http, interaction, ParrotMessage::StageTopicOff).await
+    }
+}
@@ -1,95 +1,209 @@
 use crate::{
-    connection::get_voice_channel_for_user,
-    errors::ParrotError,
-    handlers::{IdleHandler, TrackEndHandler},
+    connection::{get_listeners, get_voice_channel_for_user},
+    errors::{verify, ParrotError},
+    guild::{cache::GuildCacheMap, settings::GuildSettingsMap},
+    handlers::{
+        always_on::{PlaylistLoopHandler, PLAYLIST_LOOP_INTERVAL},
+        now_playing::NowPlayingHandler,
+        reconnect::{move_call, DriverDisconnectHandler},
+        stage::{become_speaker, is_stage_channel, StageRole},
+        IdleHandler, TrackEndHandler,
+    },
+    messaging::i18n::tr,
     messaging::message::ParrotMessage,
-    utils::create_response,
+    messaging::messages::{
+        FAIL_GUILD_NOT_CACHED, FAIL_GUILD_ONLY, FAIL_JOIN_CHANNEL, FAIL_VOICE_MANAGER,
+    },
+    utils::{create_response, mention_all},
 };
 use serenity::{
-    all::CommandInteraction, client::Context, model::id::ChannelId, prelude::Mentionable,
+    all::CommandInteraction,
+    client::Context,
+    model::id::{ChannelId, GuildId},
+    prelude::Mentionable,
 };
-use songbird::{Event, TrackEvent};
-use std::time::Duration;
+use songbird::{CoreEvent, Event, Songbird, TrackEvent};
+use std::{sync::Arc, time::Duration};
 
 pub async fn summon(
     ctx: &Context,
     interaction: &mut CommandInteraction,
     send_reply: bool,
 ) -> Result<(), ParrotError> {
-    let guild_id = interaction.guild_id.ok_or(ParrotError::Other(
-        "This command can only be used in a server",
-    ))?;
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
 
     // Clone the guild to avoid holding CacheRef across await points
     let guild = ctx
         .cache
         .guild(guild_id)
-        .ok_or(ParrotError::Other("Guild not found in cache"))?
+        .ok_or(ParrotError::Other(FAIL_GUILD_NOT_CACHED))?
         .clone();
 
     let manager = songbird::get(ctx)
         .await
-        .ok_or(ParrotError::Other("Voice manager not configured"))?;
+        .ok_or(ParrotError::Other(FAIL_VOICE_MANAGER))?;
 
     let channel_id = get_voice_channel_for_user(&guild, &interaction.user.id)
         .ok_or(ParrotError::AuthorNotFound)?;
 
-    if let Some(call) = manager.get(guild.id) {
+    check_voice_channel(ctx, guild_id, channel_id).await?;
+
+    let current_call = manager.get(guild.id);
+    let mut moving = false;
+
+    if let Some(call) = &current_call {
         let handler = call.lock().await;
         let has_current_connection = handler.current_connection().is_some();
+        let current_channel = handler.current_channel();
+        drop(handler);
 
         if has_current_connection && send_reply {
             // bot is in another channel
-            if let Some(current_channel) = handler.current_channel() {
+            if let Some(current_channel) = current_channel {
                 let bot_channel_id: ChannelId = current_channel.0.into();
-                return Err(ParrotError::AlreadyConnected(bot_channel_id.mention()));
+
+                // only moderators may take the bot away from people who are still listening
+                let can_move = interaction
+                    .member
+                    .as_ref()
+                    .and_then(|member| member.permissions)
+                    .is_some_and(|permissions| permissions.move_members())
+                    || get_listeners(&guild, bot_channel_id).is_empty();
+
+                if bot_channel_id == channel_id || !can_move {
+                    return Err(ParrotError::AlreadyConnected(bot_channel_id.mention()));
+                }
+                moving = true;
             }
         }
     }
 
-    // join the channel
-    manager
-        .join(guild.id, channel_id)
-        .await
-        .map_err(|e| ParrotError::Dynamic(format!("Failed to join channel: {}", e)))?;
+    // remember where the bot was summoned from for later announcements
+    let mut data = ctx.data.write().await;
+    let cache_map = data.get_mut::<GuildCacheMap>().unwrap();
+    cache_map.entry(guild_id).or_default().text_channel = Some(interaction.channel_id);
+    drop(data);
 
-    // unregister existing events and register idle notifier
-    if let Some(call) = manager.get(guild.id) {
-        let mut handler = call.lock().await;
+    // moving keeps the call, so the queue and its events come along for the ride
+    match current_call.filter(|_| moving) {
+        Some(call) => move_call(&call, channel_id.into()).await?,
+        None => {
+            manager
+                .join(guild.id, channel_id)
+                .await
+                .map_err(|e| ParrotError::Dynamic(format!("{} {}", tr(FAIL_JOIN_CHANNEL), e)))?;
 
-        handler.remove_all_global_events();
+            register_global_events(ctx, manager.clone(), guild.id).await;
+        }
+    }
 
-        handler.add_global_event(
-            Event::Periodic(Duration::from_secs(1), None),
-            IdleHandler {
-                http: ctx.http.clone(),
-                manager,
-                interaction: interaction.clone(),
-                limit: 60 * 10,
-                count: Default::default(),
-            },
-        );
+    let stage_role = match is_stage_channel(&guild, channel_id) {
+        true => match become_speaker(ctx, &guild, channel_id).await {
+            Ok(role) => Some(role),
+            Err(err) => {
+                // a fresh call has nothing worth keeping if nobody can hear it
+                if !moving {
+                    manager.remove(guild.id).await.ok();
+                }
+                return Err(err);
+            }
+        },
+        false => None,
+    };
 
+    if !send_reply {
+        return Ok(());
+    }
+
+    let mention = channel_id.mention();
+    let message = match (stage_role, moving) {
+        (Some(StageRole::RequestedToSpeak), _) => ParrotMessage::StageRequestedToSpeak { mention },
+        (_, true) => ParrotMessage::Moved { mention },
+        (_, false) => ParrotMessage::Summon { mention },
+    };
+
+    create_response(&ctx.http, interaction, message).await
+}
+
+/// Fails with the channels the bot may join if the given one isn't among them.
+async fn check_voice_channel(
+    ctx: &Context,
+    guild_id: GuildId,
+    channel_id: ChannelId,
+) -> Result<(), ParrotError> {
+    let data = ctx.data.read().await;
+    let settings = data.get::<GuildSettingsMap>().unwrap();
+    let Some(guild_settings) = settings.get(&guild_id) else {
+        return Ok(());
+    };
+
+    let mentions = mention_all(&guild_settings.allowed_voice_channels);
+    verify(
+        guild_settings.is_voice_channel_allowed(channel_id),
+        ParrotError::VoiceChannelNotAllowed(mentions),
+    )?;
+    Ok(())
+}
+
+/// Replaces the events registered on a call with the ones the bot relies on during playback.
+pub async fn register_global_events(ctx: &Context, manager: Arc<Songbird>, guild_id: GuildId) {
+    let Some(call) = manager.get(guild_id) else {
+        return;
+    };
+    let mut handler = call.lock().await;
+
+    handler.remove_all_global_events();
+
+    handler.add_global_event(
+        Event::Periodic(Duration::from_secs(1), None),
+        IdleHandler {
+            http: ctx.http.clone(),
+            manager,
+            guild_id,
+            ctx_data: ctx.data.clone(),
+            count: Default::default(),
+        },
+    );
+
+    handler.add_global_event(
+        Event::Track(TrackEvent::End),
+        TrackEndHandler {
+            guild_id,
+            call: call.clone(),
+            ctx_data: ctx.data.clone(),
+        },
+    );
+
+    for event in [TrackEvent::Play, TrackEvent::End] {
         handler.add_global_event(
-            Event::Track(TrackEvent::End),
-            TrackEndHandler {
-                guild_id: guild.id,
+            Event::Track(event),
+            NowPlayingHandler {
+                ctx: ctx.clone(),
+                guild_id,
                 call: call.clone(),
-                ctx_data: ctx.data.clone(),
             },
         );
     }
 
-    if send_reply {
-        return create_response(
-            &ctx.http,
-            interaction,
-            ParrotMessage::Summon {
-                mention: channel_id.mention(),
-            },
-        )
-        .await;
-    }
+    handler.add_global_event(
+        Event::Periodic(PLAYLIST_LOOP_INTERVAL, None),
+        PlaylistLoopHandler {
+            http: ctx.http.clone(),
+            guild_id,
+            call: call.clone(),
+            ctx_data: ctx.data.clone(),
+            refilling: Default::default(),
+        },
+    );
 
-    Ok(())
+    handler.add_global_event(
+        Event::Core(CoreEvent::DriverDisconnect),
+        DriverDisconnectHandler {
+            ctx: ctx.clone(),
+            guild_id,
+            call: call.clone(),
+        },
+    );
 }
@@ -0,0 +1,416 @@
+use crate::{
+    commands::{
+        cancel::cancel_imports, play::get_track_metadata, shuffle::shuffle_upcoming,
+        voteskip::required_votes,
+    },
+    connection::{get_voice_channel_for_user, get_voters},
+    errors::{verify, ParrotError},
+    guild::{
+        cache::GuildCacheMap,
+        settings::{GuildSettings, GuildSettingsMap},
+        vote::VoteAction,
+    },
+    handlers::{audit_log::log_queue_change, track_end::update_queue_messages, SerenityHandler},
+    messaging::{
+        i18n::tr,
+        message::ParrotMessage,
+        messages::{
+            FAIL_GUILD_NOT_CACHED, FAIL_GUILD_ONLY, FAIL_MISSING_SUBCOMMAND, FAIL_VOICE_MANAGER,
+            FAIL_VOTE_DEAFENED, FAIL_VOTE_IN_PROGRESS, FAIL_VOTE_TRACK_GONE, VOTE_BUTTON,
+            VOTE_CLEAR, VOTE_REMOVE, VOTE_SHUFFLE, VOTE_STOP,
+        },
+    },
+};
+use serenity::{
+    all::{
+        ButtonStyle, CommandDataOptionValue, CommandInteraction, CreateActionRow, CreateButton,
+        CreateInteractionResponse, CreateInteractionResponseMessage,
+    },
+    builder::{CreateEmbed, EditInteractionResponse},
+    client::Context,
+    futures::StreamExt,
+    model::{
+        id::{GuildId, UserId},
+        Timestamp,
+    },
+};
+use songbird::tracks::TrackHandle;
+use std::collections::HashSet;
+
+const VOTE_BUTTON_ID: &str = "vote";
+
+pub async fn vote(ctx: &Context, interaction: &mut CommandInteraction) -> Result<(), ParrotError> {
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
+
+    let subcommand = interaction
+        .data
+        .options
+        .first()
+        .ok_or(ParrotError::Other(FAIL_MISSING_SUBCOMMAND))?;
+
+    let action = match subcommand.name.as_str() {
+        "stop" => VoteAction::Stop,
+        "clear" => VoteAction::Clear,
+        "shuffle" => VoteAction::Shuffle,
+        _ => VoteAction::Remove,
+    };
+
+    let index = match &subcommand.value {
+        CommandDataOptionValue::SubCommand(sub_options) => sub_options
+            .iter()
+            .find(|opt| opt.name == "index")
+            .and_then(|opt| opt.value.as_i64())
+            .map(|index| index.max(1) as usize),
+        _ => None,
+    };
+
+    let user_id = interaction.user.id;
+    let voters = get_channel_voters(ctx, guild_id)?;
+    verify(
+        voters.contains(&user_id),
+        ParrotError::Other(FAIL_VOTE_DEAFENED),
+    )?;
+
+    let manager = songbird::get(ctx)
+        .await
+        .ok_or(ParrotError::Other(FAIL_VOICE_MANAGER))?;
+    let call = manager.get(guild_id).ok_or(ParrotError::NotConnected)?;
+    let queue = call.lock().await.queue().current_queue();
+
+    // the track is remembered rather than its position, which shifts as the queue plays
+    let target = match action {
+        VoteAction::Stop => {
+            verify(!queue.is_empty(), ParrotError::NothingPlaying)?;
+            None
+        }
+        VoteAction::Clear | VoteAction::Shuffle => {
+            verify(queue.len() > 1, ParrotError::QueueEmpty)?;
+            None
+        }
+        VoteAction::Remove => {
+            let index = index.unwrap_or(1);
+            verify(queue.len() > 1, ParrotError::QueueEmpty)?;
+            let track = queue.get(index).cloned();
+            Some(verify(
+                track,
+                ParrotError::NotInRange("index", index as isize, 1, queue.len() as isize),
+            )?)
+        }
+    };
+
+    let percent = {
+        let mut data = ctx.data.write().await;
+        let settings = data.get::<GuildSettingsMap>().unwrap();
+        let default_settings = GuildSettings::new(guild_id);
+        let percent = settings
+            .get(&guild_id)
+            .unwrap_or(&default_settings)
+            .vote_percents
+            .get(action.name())
+            .copied()
+            .unwrap_or(action.default_percent());
+
+        let cache_map = data.get_mut::<GuildCacheMap>().unwrap();
+        let cache = cache_map.entry(guild_id).or_default();
+
+        verify(
+            !cache.active_votes.contains_key(&action),
+            ParrotError::Other(FAIL_VOTE_IN_PROGRESS),
+        )?;
+        cache.active_votes.insert(action, HashSet::from([user_id]));
+        percent
+    };
+
+    let result = hold_vote(
+        ctx,
+        interaction,
+        guild_id,
+        action,
+        target,
+        voters.len(),
+        percent,
+    )
+    .await;
+
+    // whether it passed, expired or failed to reach Discord, the vote must not stay open
+    forget_vote(ctx, guild_id, action).await;
+    result
+}
+
+/// Runs a vote that was just opened until it passes or expires.
+async fn hold_vote(
+    ctx: &Context,
+    interaction: &mut CommandInteraction,
+    guild_id: GuildId,
+    action: VoteAction,
+    target: Option<TrackHandle>,
+    voters: usize,
+    percent: u8,
+) -> Result<(), ParrotError> {
+    let description = describe_action(action, target.as_ref());
+
+    // a lone listener doesn't need to wait for anyone
+    if required_votes(voters, percent) <= 1 {
+        let message = vote_outcome(ctx, interaction, guild_id, action, target.as_ref()).await;
+        let embed = CreateEmbed::new().description(message);
+        let response = CreateInteractionResponse::Message(
+            CreateInteractionResponseMessage::new().add_embed(embed),
+        );
+        interaction.create_response(&ctx.http, response).await?;
+        return Ok(());
+    }
+
+    let ends = Timestamp::now().unix_timestamp() + action.expiry().as_secs() as i64;
+    let tally = ParrotMessage::VoteTally {
+        action: description.clone(),
+        votes: 1,
+        required: required_votes(voters, percent),
+        ends,
+    };
+
+    let response = CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new()
+            .add_embed(CreateEmbed::new().description(tally.to_string()))
+            .components(vec![build_vote_btn()]),
+    );
+    interaction.create_response(&ctx.http, response).await?;
+
+    let message = interaction.get_response(&ctx.http).await?;
+    let mut collector = message
+        .await_component_interactions(ctx)
+        .custom_ids(vec![VOTE_BUTTON_ID.to_string()])
+        .timeout(action.expiry())
+        .stream();
+
+    let mut passed = false;
+
+    while let Some(mci) = collector.next().await {
+        // pressing the button stands in for running the command, so it's held to the same rules
+        let checked = SerenityHandler
+            .check_button(ctx, guild_id, &interaction.data.name, &mci)
+            .await;
+        let cast = match checked {
+            Ok(()) => cast_vote(ctx, guild_id, action, mci.user.id).await,
+            Err(err) => Err(err),
+        };
+
+        let tally = match cast {
+            Ok(tally) => tally,
+            Err(err) => {
+                let response = CreateInteractionResponse::Message(
+                    CreateInteractionResponseMessage::new()
+                        .add_embed(CreateEmbed::new().description(err.to_string()))
+                        .ephemeral(true),
+                );
+                mci.create_response(&ctx.http, response).await?;
+                continue;
+            }
+        };
+
+        let (votes, voters) = tally;
+        let required = required_votes(voters, percent);
+        passed = votes >= required;
+
+        let (content, components) = match passed {
+            true => {
+                let outcome =
+                    vote_outcome(ctx, interaction, guild_id, action, target.as_ref()).await;
+                (outcome, vec![])
+            }
+            false => {
+                let tally = ParrotMessage::VoteTally {
+                    action: description.clone(),
+                    votes,
+                    required,
+                    ends,
+                };
+                (tally.to_string(), vec![build_vote_btn()])
+            }
+        };
+
+        let response = CreateInteractionResponse::UpdateMessage(
+            CreateInteractionResponseMessage::new()
+                .add_embed(CreateEmbed::new().description(content))
+                .components(components),
+        );
+        mci.create_response(&ctx.http, response).await?;
+
+        if passed {
+            break;
+        }
+    }
+
+    if !passed {
+        let expired = ParrotMessage::VoteExpired {
+            action: description,
+        };
+        let edit = EditInteractionResponse::new()
+            .embed(CreateEmbed::new().description(expired.to_string()))
+            .components(vec![]);
+        if let Err(e) = interaction.edit_response(&ctx.http, edit).await {
+            eprintln!("[WARN] Failed to edit vote message: {}", e);
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the users in the bot's voice channel who are allowed to vote.
+fn get_channel_voters(ctx: &Context, guild_id: GuildId) -> Result<Vec<UserId>, ParrotError> {
+    let guild = ctx
+        .cache
+        .guild(guild_id)
+        .ok_or(ParrotError::Other(FAIL_GUILD_NOT_CACHED))?;
+    let bot_channel_id = get_voice_channel_for_user(&guild, &ctx.cache.current_user().id)
+        .ok_or(ParrotError::NotConnected)?;
+
+    Ok(get_voters(&guild, bot_channel_id))
+}
+
+/// Adds a user's vote, returning the number of votes along with the number of voters.
+async fn cast_vote(
+    ctx: &Context,
+    guild_id: GuildId,
+    action: VoteAction,
+    user_id: UserId,
+) -> Result<(usize, usize), ParrotError> {
+    let voters = get_channel_voters(ctx, guild_id)?;
+    verify(voters.contains(&user_id), ParrotError::WrongVoiceChannel)?;
+
+    let mut data = ctx.data.write().await;
+    let cache_map = data.get_mut::<GuildCacheMap>().unwrap();
+    let votes = cache_map
+        .entry(guild_id)
+        .or_default()
+        .active_votes
+        .entry(action)
+        .or_default();
+
+    // whoever left the channel since voting no longer has a say
+    votes.retain(|voter| voters.contains(voter));
+    votes.insert(user_id);
+
+    Ok((votes.len(), voters.len()))
+}
+
+async fn forget_vote(ctx: &Context, guild_id: GuildId, action: VoteAction) {
+    let mut data = ctx.data.write().await;
+    let cache_map = data.get_mut::<GuildCacheMap>().unwrap();
+
+    if let Some(cache) = cache_map.get_mut(&guild_id) {
+        cache.active_votes.remove(&action);
+    }
+}
+
+/// Carries out a vote that passed, describing what happened or why it couldn't be done.
+async fn vote_outcome(
+    ctx: &Context,
+    interaction: &CommandInteraction,
+    guild_id: GuildId,
+    action: VoteAction,
+    target: Option<&TrackHandle>,
+) -> String {
+    match apply_vote(ctx, interaction, guild_id, action, target).await {
+        Ok(outcome) => ParrotMessage::VotePassed {
+            outcome: Box::new(outcome),
+        }
+        .to_string(),
+        Err(err) => err.to_string(),
+    }
+}
+
+async fn apply_vote(
+    ctx: &Context,
+    interaction: &CommandInteraction,
+    guild_id: GuildId,
+    action: VoteAction,
+    target: Option<&TrackHandle>,
+) -> Result<ParrotMessage, ParrotError> {
+    let manager = songbird::get(ctx)
+        .await
+        .ok_or(ParrotError::Other(FAIL_VOICE_MANAGER))?;
+    let call = manager.get(guild_id).ok_or(ParrotError::NotConnected)?;
+
+    // pending imports would otherwise keep refilling the queue
+    if matches!(action, VoteAction::Stop | VoteAction::Clear) {
+        cancel_imports(&ctx.data, guild_id).await;
+    }
+
+    let handler = call.lock().await;
+    let queue = handler.queue();
+    let before = queue.current_queue();
+
+    let message = match action {
+        VoteAction::Stop => {
+            verify(!queue.is_empty(), ParrotError::NothingPlaying)?;
+            queue.stop();
+            ParrotMessage::Stop
+        }
+        VoteAction::Clear => {
+            verify(queue.len() > 1, ParrotError::QueueEmpty)?;
+            queue.modify_queue(|v| {
+                v.drain(1..);
+            });
+            ParrotMessage::Clear
+        }
+        VoteAction::Shuffle => {
+            shuffle_upcoming(queue);
+            ParrotMessage::Shuffle
+        }
+        VoteAction::Remove => {
+            let target = target.ok_or(ParrotError::Other(FAIL_VOTE_TRACK_GONE))?;
+            let position = queue
+                .current_queue()
+                .iter()
+                .skip(1)
+                .position(|track| track.uuid() == target.uuid())
+                .ok_or(ParrotError::Other(FAIL_VOTE_TRACK_GONE))?;
+
+            queue.modify_queue(|v| {
+                v.remove(position + 1);
+            });
+            ParrotMessage::VoteRemoved {
+                title: track_title(target),
+            }
+        }
+    };
+
+    // refetch the queue after modification
+    let queue = handler.queue().current_queue();
+    drop(handler);
+
+    update_queue_messages(&ctx.http, &ctx.data, &queue, guild_id).await;
+
+    // attributed to whoever started the vote, as the command it stood in for
+    let command_name = format!("{} {}", interaction.data.name, action.name());
+    log_queue_change(ctx, guild_id, interaction.user.id, &command_name, before).await;
+    Ok(message)
+}
+
+fn describe_action(action: VoteAction, target: Option<&TrackHandle>) -> String {
+    match action {
+        VoteAction::Stop => tr(VOTE_STOP).to_string(),
+        VoteAction::Clear => tr(VOTE_CLEAR).to_string(),
+        VoteAction::Shuffle => tr(VOTE_SHUFFLE).to_string(),
+        VoteAction::Remove => format!(
+            "{} **{}**",
+            tr(VOTE_REMOVE),
+            target.map(track_title).unwrap_or_default()
+        ),
+    }
+}
+
+fn track_title(track: &TrackHandle) -> String {
+    get_track_metadata(track)
+        .and_then(|metadata| metadata.title)
+        .unwrap_or_else(|| "Unknown".to_string())
+}
+
+fn build_vote_btn() -> CreateActionRow {
+    let button = CreateButton::new(VOTE_BUTTON_ID)
+        .label(tr(VOTE_BUTTON))
+        .style(ButtonStyle::Primary);
+    CreateActionRow::Buttons(vec![button])
+}
@@ -0,0 +1,53 @@
+use crate::{
+    errors::ParrotError,
+    guild::settings::{GuildSettings, GuildSettingsMap},
+    messaging::message::ParrotMessage,
+    messaging::messages::{FAIL_GUILD_ONLY, FAIL_MISSING_VOTE_PERCENT},
+    utils::create_response,
+};
+use serenity::{all::CommandInteraction, client::Context};
+
+pub async fn votepercent(
+    ctx: &Context,
+    interaction: &mut CommandInteraction,
+) -> Result<(), ParrotError> {
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
+
+    let get_option = |name: &str| {
+        interaction
+            .data
+            .options
+            .iter()
+            .find(|opt| opt.name == name)
+            .map(|opt| &opt.value)
+    };
+
+    let percent = get_option("percent")
+        .and_then(|value| value.as_i64())
+        .ok_or(ParrotError::Other(FAIL_MISSING_VOTE_PERCENT))?;
+    let vote = get_option("vote")
+        .and_then(|value| value.as_str())
+        .unwrap_or("skip")
+        .to_string();
+
+    let mut data = ctx.data.write().await;
+    let settings = data.get_mut::<GuildSettingsMap>().unwrap();
+
+    let guild_settings = settings
+        .entry(guild_id)
+        .or_insert_with(|| GuildSettings::new(guild_id));
+    // out of range values are clamped by the settings
+    let percent = u8::try_from(percent).unwrap_or(u8::MAX);
+    let percent = guild_settings.set_vote_percent(&vote, percent);
+    guild_settings.save()?;
+    drop(data);
+
+    create_response(
+        &ctx.http,
+        interaction,
+        ParrotMessage::VotePercent { vote, percent },
+    )
+    .await
+}
@@ -1,75 +1,182 @@
 use crate::{
-    commands::skip::{create_skip_response, force_skip_top_track},
-    connection::get_voice_channel_for_user,
+    commands::{
+        play::get_track_requester,
+        skip::{create_skip_message, force_skip_top_track},
+    },
+    connection::{get_voice_channel_for_user, get_voters},
     errors::{verify, ParrotError},
-    guild::cache::GuildCacheMap,
-    messaging::message::ParrotMessage,
+    guild::{
+        cache::GuildCacheMap,
+        settings::{GuildSettings, GuildSettingsMap},
+    },
+    handlers::audit_log::log_queue_change,
+    messaging::{
+        message::ParrotMessage,
+        messages::{
+            FAIL_GUILD_CACHE, FAIL_GUILD_NOT_CACHED, FAIL_GUILD_ONLY, FAIL_VOICE_MANAGER,
+            FAIL_VOTE_DEAFENED, FAIL_VOTE_TRACK_GONE,
+        },
+    },
     utils::create_response,
 };
 use serenity::{
     all::CommandInteraction,
     client::Context,
-    model::id::GuildId,
-    prelude::{Mentionable, RwLock, TypeMap},
+    model::id::{GuildId, UserId},
+    prelude::{Mentionable, Mutex, RwLock, TypeMap},
 };
+use songbird::{tracks::TrackHandle, Call};
 use std::{collections::HashSet, sync::Arc};
 
+/// Custom ID of the button on now playing messages that casts a vote to skip.
+pub const VOTESKIP_BUTTON_ID: &str = "voteskip";
+
 pub async fn voteskip(
     ctx: &Context,
     interaction: &mut CommandInteraction,
 ) -> Result<(), ParrotError> {
-    let guild_id = interaction.guild_id.ok_or(ParrotError::Other(
-        "This command can only be used in a server",
-    ))?;
+    let guild_id = interaction
+        .guild_id
+        .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
+
+    let message = cast_skip_vote(ctx, guild_id, interaction.user.id).await?;
+    create_response(&ctx.http, interaction, message).await
+}
+
+/// Counts a vote to skip the current track and skips it once enough listeners agree.
+/// Whoever requested the track may skip it without asking anyone else.
+pub async fn cast_skip_vote(
+    ctx: &Context,
+    guild_id: GuildId,
+    user_id: UserId,
+) -> Result<ParrotMessage, ParrotError> {
     let guild = ctx
         .cache
         .guild(guild_id)
-        .ok_or(ParrotError::Other("Guild not found in cache"))?
+        .ok_or(ParrotError::Other(FAIL_GUILD_NOT_CACHED))?
         .clone();
     let bot_channel_id = get_voice_channel_for_user(&guild, &ctx.cache.current_user().id)
         .ok_or(ParrotError::NotConnected)?;
+
+    // the button can be pressed from anywhere, unlike the command
+    let user_channel_id = get_voice_channel_for_user(&guild, &user_id);
+    verify(
+        user_channel_id == Some(bot_channel_id),
+        ParrotError::WrongVoiceChannel,
+    )?;
+
+    let voters = get_voters(&guild, bot_channel_id);
+    let percent = {
+        let data = ctx.data.read().await;
+        let settings = data.get::<GuildSettingsMap>().unwrap();
+        let default_settings = GuildSettings::new(guild_id);
+        settings
+            .get(&guild_id)
+            .unwrap_or(&default_settings)
+            .skip_vote_percent
+    };
+
     let manager = songbird::get(ctx)
         .await
-        .ok_or(ParrotError::Other("Voice manager not configured"))?;
+        .ok_or(ParrotError::Other(FAIL_VOICE_MANAGER))?;
     let call = manager.get(guild_id).ok_or(ParrotError::NotConnected)?;
 
-    let handler = call.lock().await;
-    let queue = handler.queue();
+    // the call is only locked to act on the queue, never while waiting on the data lock
+    let track = call
+        .lock()
+        .await
+        .queue()
+        .current()
+        .ok_or(ParrotError::NothingPlaying)?;
+
+    if get_track_requester(&track) == Some(user_id) {
+        return skip_voted_track(ctx, guild_id, user_id, &call, &track).await;
+    }
 
-    verify(!queue.is_empty(), ParrotError::NothingPlaying)?;
+    verify(
+        voters.contains(&user_id),
+        ParrotError::Other(FAIL_VOTE_DEAFENED),
+    )?;
 
     let mut data = ctx.data.write().await;
     let cache_map = data
         .get_mut::<GuildCacheMap>()
-        .ok_or(ParrotError::Other("Guild cache not initialized"))?;
+        .ok_or(ParrotError::Other(FAIL_GUILD_CACHE))?;
 
     let cache = cache_map.entry(guild_id).or_default();
-    cache.current_skip_votes.insert(interaction.user.id);
+    cache
+        .current_skip_votes
+        .retain(|voter| voters.contains(voter));
+    cache.current_skip_votes.insert(user_id);
 
-    let guild_users = ctx
-        .cache
-        .guild(guild_id)
-        .ok_or(ParrotError::Other("Guild not found in cache"))?
-        .voice_states
-        .clone();
-    let channel_guild_users = guild_users
-        .into_values()
-        .filter(|v| v.channel_id == Some(bot_channel_id));
-    let skip_threshold = channel_guild_users.count() / 2;
-
-    if cache.current_skip_votes.len() >= skip_threshold {
-        force_skip_top_track(&handler).await?;
-        create_skip_response(ctx, interaction, &handler, 1).await
+    let votes = cache.current_skip_votes.len();
+    let required = required_votes(voters.len(), percent);
+    drop(data);
+
+    if votes >= required {
+        skip_voted_track(ctx, guild_id, user_id, &call, &track).await
     } else {
-        create_response(
-            &ctx.http,
-            interaction,
-            ParrotMessage::VoteSkip {
-                mention: interaction.user.id.mention(),
-                missing: skip_threshold - cache.current_skip_votes.len(),
-            },
-        )
-        .await
+        Ok(ParrotMessage::VoteSkip {
+            mention: user_id.mention(),
+            missing: required - votes,
+        })
+    }
+}
+
+/// Skips the track that was voted on, unless it already ended since and another one took over.
+async fn skip_voted_track(
+    ctx: &Context,
+    guild_id: GuildId,
+    user_id: UserId,
+    call: &Arc<Mutex<Call>>,
+    track: &TrackHandle,
+) -> Result<ParrotMessage, ParrotError> {
+    let handler = call.lock().await;
+    let current = handler.queue().current();
+    verify(
+        current.is_some_and(|current| current.uuid() == track.uuid()),
+        ParrotError::Other(FAIL_VOTE_TRACK_GONE),
+    )?;
+
+    let before = handler.queue().current_queue();
+    force_skip_top_track(&handler).await?;
+    let message = create_skip_message(&handler, 1);
+    drop(handler);
+
+    // attributed to whoever cast the deciding vote or skipped their own track
+    log_queue_change(ctx, guild_id, user_id, "voteskip", before).await;
+    Ok(message)
+}
+
+/// Returns how many votes it takes to skip among the given number of voters.
+pub fn required_votes(voters: usize, percent: u8) -> usize {
+    (voters * percent as usize).div_ceil(100).max(1)
+}
+
+/// Drops the votes of anyone who left the channel or can no longer hear it.
+pub async fn forget_absent_votes(ctx: &Context, guild_id: GuildId) {
+    let bot_id = ctx.cache.current_user().id;
+    let voters = ctx.cache.guild(guild_id).map(|guild| {
+        get_voice_channel_for_user(&guild, &bot_id)
+            .map(|channel_id| get_voters(&guild, channel_id))
+            .unwrap_or_default()
+    });
+
+    let Some(voters) = voters else {
+        return;
+    };
+
+    let mut data = ctx.data.write().await;
+    let cache_map = data.get_mut::<GuildCacheMap>().unwrap();
+
+    if let Some(cache) = cache_map.get_mut(&guild_id) {
+        cache
+            .current_skip_votes
+            .retain(|voter| voters.contains(voter));
+
+        for votes in cache.active_votes.values_mut() {
+            votes.retain(|voter| voters.contains(voter));
+        }
     }
 }
 
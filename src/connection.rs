@@ -1,6 +1,7 @@
 use serenity::model::{
     guild::Guild,
     id::{ChannelId, UserId},
+    voice::VoiceState,
 };
 
 pub enum Connection {
@@ -36,3 +37,36 @@ pub fn get_voice_channel_for_user(guild: &Guild, user_id: &UserId) -> Option<Cha
         .get(user_id)
         .and_then(|voice_state| voice_state.channel_id)
 }
+
+/// Returns the users connected to a voice channel that aren't bots.
+pub fn get_listeners(guild: &Guild, channel_id: ChannelId) -> Vec<UserId> {
+    guild
+        .voice_states
+        .values()
+        .filter(|voice_state| voice_state.channel_id == Some(channel_id))
+        .filter(|voice_state| !is_bot(guild, voice_state))
+        .map(|voice_state| voice_state.user_id)
+        .collect()
+}
+
+fn is_bot(guild: &Guild, voice_state: &VoiceState) -> bool {
+    voice_state
+        .member
+        .as_ref()
+        .or_else(|| guild.members.get(&voice_state.user_id))
+        .map(|member| member.user.bot)
+        .unwrap_or_default()
+}
+
+/// Returns the listeners in a voice channel that can actually hear what's playing.
+pub fn get_voters(guild: &Guild, channel_id: ChannelId) -> Vec<UserId> {
+    get_listeners(guild, channel_id)
+        .into_iter()
+        .filter(|user_id| {
+            guild
+                .voice_states
+                .get(user_id)
+                .is_some_and(|voice_state| !voice_state.deaf && !voice_state.self_deaf)
+        })
+        .collect()
+}
@@ -1,11 +1,24 @@
-use crate::messaging::messages::{
-    FAIL_ANOTHER_CHANNEL, FAIL_AUTHOR_DISCONNECTED, FAIL_AUTHOR_NOT_FOUND,
-    FAIL_NO_VOICE_CONNECTION, FAIL_WRONG_CHANNEL, NOTHING_IS_PLAYING, QUEUE_IS_EMPTY,
-    TRACK_NOT_FOUND,
+use crate::guild::{
+    filter::FilterRule,
+    settings::{BanScope, CommandPolicy, MusicBan},
+};
+use crate::messaging::{
+    i18n::tr,
+    message::format_ban_expiry,
+    messages::{
+        FAIL_ANOTHER_CHANNEL, FAIL_AUTHOR_DISCONNECTED, FAIL_AUTHOR_NOT_FOUND,
+        FAIL_COMMAND_ADMIN_ONLY, FAIL_COMMAND_DISABLED, FAIL_COMMAND_DJ_ONLY, FAIL_INVALID_DOMAINS,
+        FAIL_MUSIC_BANNED, FAIL_MUSIC_BANNED_QUEUE, FAIL_NOT_DJ, FAIL_NOT_IN_RANGE,
+        FAIL_NOT_IN_RANGE_AND, FAIL_NOT_IN_RANGE_VALUE, FAIL_NO_VOICE_CONNECTION,
+        FAIL_RATE_LIMITED, FAIL_TEXT_CHANNEL_NOT_ALLOWED, FAIL_TRACK_FILTERED_PREFIX,
+        FAIL_TRACK_FILTERED_SUFFIX, FAIL_VOICE_CHANNEL_NOT_ALLOWED, FAIL_WRONG_CHANNEL,
+        NOTHING_IS_PLAYING, QUEUE_IS_EMPTY, TRACK_NOT_FOUND,
+    },
 };
 use rspotify::ClientError as RSpotifyClientError;
 use serenity::{model::mention::Mention, prelude::SerenityError};
 use std::fmt::{Debug, Display};
+use std::time::Duration;
 use std::{error::Error, fmt};
 
 /// A common error enum returned by most of the crate's functions within a [`Result`].
@@ -22,6 +35,14 @@ pub enum ParrotError {
     NothingPlaying,
     TrackFail(String),
     AlreadyConnected(Mention),
+    NotDj,
+    CommandNotAllowed(CommandPolicy),
+    InvalidDomains(Vec<String>),
+    MusicBanned(MusicBan),
+    RateLimited(Duration),
+    TextChannelNotAllowed(Vec<Mention>),
+    TrackFiltered(FilterRule),
+    VoiceChannelNotAllowed(Vec<Mention>),
     Serenity(Box<SerenityError>),
     RSpotify(RSpotifyClientError),
     IO(std::io::Error),
@@ -38,25 +59,71 @@ impl Error for ParrotError {}
 impl Display for ParrotError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::Other(msg) => f.write_str(msg),
+            Self::Other(msg) => f.write_str(tr(msg)),
             Self::Dynamic(msg) => f.write_str(msg),
-            Self::QueueEmpty => f.write_str(QUEUE_IS_EMPTY),
+            Self::QueueEmpty => f.write_str(tr(QUEUE_IS_EMPTY)),
             Self::NotInRange(param, value, lower, upper) => f.write_str(&format!(
-                "`{param}` should be between {lower} and {upper} but was {value}"
+                "`{}` {} {} {} {} {} {}",
+                param,
+                tr(FAIL_NOT_IN_RANGE),
+                lower,
+                tr(FAIL_NOT_IN_RANGE_AND),
+                upper,
+                tr(FAIL_NOT_IN_RANGE_VALUE),
+                value
             )),
-            Self::NotConnected => f.write_str(FAIL_NO_VOICE_CONNECTION),
+            Self::NotConnected => f.write_str(tr(FAIL_NO_VOICE_CONNECTION)),
             Self::AuthorDisconnected(mention) => {
-                f.write_fmt(format_args!("{} {}", FAIL_AUTHOR_DISCONNECTED, mention))
+                f.write_fmt(format_args!("{} {}", tr(FAIL_AUTHOR_DISCONNECTED), mention))
             }
-            Self::WrongVoiceChannel => f.write_str(FAIL_WRONG_CHANNEL),
-            Self::AuthorNotFound => f.write_str(FAIL_AUTHOR_NOT_FOUND),
+            Self::WrongVoiceChannel => f.write_str(tr(FAIL_WRONG_CHANNEL)),
+            Self::AuthorNotFound => f.write_str(tr(FAIL_AUTHOR_NOT_FOUND)),
             Self::AlreadyConnected(mention) => {
-                f.write_fmt(format_args!("{} {}", FAIL_ANOTHER_CHANNEL, mention))
+                f.write_fmt(format_args!("{} {}", tr(FAIL_ANOTHER_CHANNEL), mention))
+            }
+            Self::NotDj => f.write_str(tr(FAIL_NOT_DJ)),
+            Self::CommandNotAllowed(policy) => match policy {
+                CommandPolicy::Dj => f.write_str(tr(FAIL_COMMAND_DJ_ONLY)),
+                CommandPolicy::Admin => f.write_str(tr(FAIL_COMMAND_ADMIN_ONLY)),
+                _ => f.write_str(tr(FAIL_COMMAND_DISABLED)),
+            },
+            Self::InvalidDomains(domains) => f.write_fmt(format_args!(
+                "{} `{}`",
+                tr(FAIL_INVALID_DOMAINS),
+                domains.join("`, `")
+            )),
+            Self::MusicBanned(ban) => {
+                let restriction = match ban.scope {
+                    BanScope::All => tr(FAIL_MUSIC_BANNED),
+                    BanScope::Queue => tr(FAIL_MUSIC_BANNED_QUEUE),
+                };
+                f.write_fmt(format_args!("{}{}!", restriction, format_ban_expiry(ban)))
             }
-            Self::NothingPlaying => f.write_str(NOTHING_IS_PLAYING),
+            Self::RateLimited(left) => f.write_fmt(format_args!(
+                "{} **{}s**!",
+                tr(FAIL_RATE_LIMITED),
+                left.as_secs_f64().ceil()
+            )),
+            Self::TextChannelNotAllowed(channels) => f.write_fmt(format_args!(
+                "{} {}!",
+                tr(FAIL_TEXT_CHANNEL_NOT_ALLOWED),
+                join_mentions(channels)
+            )),
+            Self::VoiceChannelNotAllowed(channels) => f.write_fmt(format_args!(
+                "{} {}!",
+                tr(FAIL_VOICE_CHANNEL_NOT_ALLOWED),
+                join_mentions(channels)
+            )),
+            Self::TrackFiltered(rule) => f.write_fmt(format_args!(
+                "{} **{}** {}",
+                tr(FAIL_TRACK_FILTERED_PREFIX),
+                rule,
+                tr(FAIL_TRACK_FILTERED_SUFFIX)
+            )),
+            Self::NothingPlaying => f.write_str(tr(NOTHING_IS_PLAYING)),
             Self::TrackFail(err) => {
                 if err.is_empty() {
-                    f.write_str(TRACK_NOT_FOUND)
+                    f.write_str(tr(TRACK_NOT_FOUND))
                 } else {
                     f.write_str(err)
                 }
@@ -69,6 +136,14 @@ impl Display for ParrotError {
     }
 }
 
+fn join_mentions(mentions: &[Mention]) -> String {
+    mentions
+        .iter()
+        .map(|mention| mention.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 /// Implementation of the [`PartialEq`] trait for the [`ParrotError`] enum.
 /// For some enum variants, values are considered equal when their inner values
 /// are equal and for others when they are of the same type.
@@ -87,6 +162,10 @@ impl PartialEq for ParrotError {
             }
             (Self::Serenity(l0), Self::Serenity(r0)) => format!("{l0:?}") == format!("{r0:?}"),
             (Self::TrackFail(l0), Self::TrackFail(r0)) => l0 == r0,
+            (Self::CommandNotAllowed(l0), Self::CommandNotAllowed(r0)) => l0 == r0,
+            (Self::TrackFiltered(l0), Self::TrackFiltered(r0)) => l0 == r0,
+            (Self::InvalidDomains(l0), Self::InvalidDomains(r0)) => l0 == r0,
+            (Self::MusicBanned(l0), Self::MusicBanned(r0)) => l0 == r0,
             _ => core::mem::discriminant(self) == core::mem::discriminant(other),
         }
     }
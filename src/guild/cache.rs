@@ -6,10 +6,13 @@ use std::{
 use serenity::{
     model::{
         channel::Message,
-        id::{GuildId, UserId},
+        id::{ChannelId, GuildId, InteractionId, UserId},
     },
     prelude::{RwLock, TypeMapKey},
 };
+use tokio_util::sync::CancellationToken;
+
+use crate::guild::{rate_limit::RateLimiter, vote::VoteAction};
 
 type QueueMessage = (Message, Arc<RwLock<usize>>);
 
@@ -17,6 +20,15 @@ type QueueMessage = (Message, Arc<RwLock<usize>>);
 pub struct GuildCache {
     pub queue_messages: Vec<QueueMessage>,
     pub current_skip_votes: HashSet<UserId>,
+    pub active_votes: HashMap<VoteAction, HashSet<UserId>>,
+    pub pending_imports: HashMap<InteractionId, CancellationToken>,
+    pub text_channel: Option<ChannelId>,
+    pub autoleave_timer: Option<CancellationToken>,
+    pub auto_paused: bool,
+    pub always_on_rejoin: Option<CancellationToken>,
+    pub reconnecting: bool,
+    pub now_playing: Option<String>,
+    pub rate_limiter: RateLimiter,
 }
 
 pub struct GuildCacheMap;
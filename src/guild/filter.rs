@@ -0,0 +1,134 @@
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use songbird::input::AuxMetadata;
+use std::{fmt::Display, sync::OnceLock, time::Duration};
+
+use crate::{
+    errors::ParrotError,
+    messaging::i18n::tr,
+    messaging::messages::{
+        FILTER_RULE_AGE_RESTRICTED, FILTER_RULE_CHANNEL, FILTER_RULE_LIVESTREAM,
+        FILTER_RULE_MAX_DURATION, FILTER_RULE_TITLE,
+    },
+    utils::get_human_readable_timestamp,
+};
+
+/// Per-guild rules that keep unwanted tracks out of the queue.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ContentFilter {
+    #[serde(default)]
+    pub title_pattern: Option<String>,
+    #[serde(default)]
+    pub channel_pattern: Option<String>,
+    #[serde(default)]
+    pub block_livestreams: bool,
+    #[serde(default)]
+    pub block_age_restricted: bool,
+    #[serde(default)]
+    pub max_duration: Option<u64>,
+    #[serde(skip)]
+    compiled: OnceLock<CompiledPatterns>,
+}
+
+/// The title and channel patterns, compiled the first time a track is checked.
+#[derive(Clone, Debug, Default)]
+struct CompiledPatterns {
+    title: Option<Regex>,
+    channel: Option<Regex>,
+}
+
+/// The filter rule a track was refused by.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FilterRule {
+    Title,
+    Channel,
+    Livestream,
+    AgeRestricted,
+    MaxDuration(u64),
+}
+
+impl Display for FilterRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Title => f.write_str(tr(FILTER_RULE_TITLE)),
+            Self::Channel => f.write_str(tr(FILTER_RULE_CHANNEL)),
+            Self::Livestream => f.write_str(tr(FILTER_RULE_LIVESTREAM)),
+            Self::AgeRestricted => f.write_str(tr(FILTER_RULE_AGE_RESTRICTED)),
+            Self::MaxDuration(seconds) => f.write_str(&format!(
+                "{} ({})",
+                tr(FILTER_RULE_MAX_DURATION),
+                get_human_readable_timestamp(Some(Duration::from_secs(*seconds)))
+            )),
+        }
+    }
+}
+
+impl ContentFilter {
+    /// Keyword patterns are matched regardless of case.
+    pub fn compile(pattern: &str) -> Result<Regex, ParrotError> {
+        RegexBuilder::new(pattern)
+            .case_insensitive(true)
+            .build()
+            .map_err(|err| ParrotError::Dynamic(err.to_string()))
+    }
+
+    /// Sets the title pattern after validating it, or clears it if `None`.
+    pub fn set_title_pattern(&mut self, pattern: Option<String>) -> Result<(), ParrotError> {
+        if let Some(pattern) = &pattern {
+            Self::compile(pattern)?;
+        }
+        self.title_pattern = pattern;
+        self.compiled = OnceLock::new();
+        Ok(())
+    }
+
+    /// Sets the channel pattern after validating it, or clears it if `None`.
+    pub fn set_channel_pattern(&mut self, pattern: Option<String>) -> Result<(), ParrotError> {
+        if let Some(pattern) = &pattern {
+            Self::compile(pattern)?;
+        }
+        self.channel_pattern = pattern;
+        self.compiled = OnceLock::new();
+        Ok(())
+    }
+
+    fn patterns(&self) -> &CompiledPatterns {
+        self.compiled.get_or_init(|| {
+            let compile = |pattern: &Option<String>| Self::compile(pattern.as_deref()?).ok();
+            CompiledPatterns {
+                title: compile(&self.title_pattern),
+                channel: compile(&self.channel_pattern),
+            }
+        })
+    }
+
+    /// Returns the first rule the track breaks, if any.
+    /// Livestreams are recognized by not having a duration.
+    pub fn check(&self, metadata: &AuxMetadata, age_limit: Option<u64>) -> Result<(), FilterRule> {
+        let matches = |regex: &Option<Regex>, value: &Option<String>| match (regex, value) {
+            (Some(regex), Some(value)) => regex.is_match(value),
+            _ => false,
+        };
+
+        let patterns = self.patterns();
+        if matches(&patterns.title, &metadata.title) {
+            return Err(FilterRule::Title);
+        }
+
+        if matches(&patterns.channel, &metadata.channel) {
+            return Err(FilterRule::Channel);
+        }
+
+        if self.block_age_restricted && age_limit.is_some_and(|limit| limit > 0) {
+            return Err(FilterRule::AgeRestricted);
+        }
+
+        match (metadata.duration, self.max_duration) {
+            (None, _) if self.block_livestreams => Err(FilterRule::Livestream),
+            (Some(duration), Some(max)) if duration.as_secs() > max => {
+                Err(FilterRule::MaxDuration(max))
+            }
+            _ => Ok(()),
+        }
+    }
+}
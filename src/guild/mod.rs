@@ -1,2 +1,5 @@
 pub mod cache;
+pub mod filter;
+pub mod rate_limit;
 pub mod settings;
+pub mod vote;
@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+use serenity::model::id::UserId;
+use std::{
+    collections::{HashMap, VecDeque},
+    time::{Duration, Instant},
+};
+
+/// Commands that change the queue and thereby cause the queue messages to be edited.
+pub const QUEUE_MODIFYING_COMMANDS: [&str; 9] = [
+    "clear",
+    "play",
+    "remove",
+    "shuffle",
+    "skip",
+    "stop",
+    "superplay",
+    "vote",
+    "voteskip",
+];
+
+/// Longest cooldown or rate limit window that can be configured, in seconds.
+pub const MAX_COOLDOWN: u64 = 60 * 60;
+
+/// How many queue-modifying commands a guild may run within a window of seconds.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct QueueRateLimit {
+    pub commands: u32,
+    pub window: u64,
+}
+
+/// Remembers recent command use in a guild to enforce cooldowns and rate limits.
+#[derive(Default)]
+pub struct RateLimiter {
+    last_used: HashMap<(UserId, String), Instant>,
+    queue_commands: VecDeque<Instant>,
+}
+
+impl RateLimiter {
+    /// Returns how much longer the user has to wait before running the command again.
+    pub fn cooldown_left(
+        &self,
+        user_id: UserId,
+        command: &str,
+        cooldown: Duration,
+        now: Instant,
+    ) -> Option<Duration> {
+        let last_used = self.last_used.get(&(user_id, command.to_string()))?;
+        cooldown
+            .checked_sub(now.duration_since(*last_used))
+            .filter(|left| !left.is_zero())
+    }
+
+    /// Returns how long it takes until the guild may modify its queue again.
+    pub fn rate_limit_left(&mut self, limit: QueueRateLimit, now: Instant) -> Option<Duration> {
+        let window = Duration::from_secs(limit.window);
+        while let Some(oldest) = self.queue_commands.front() {
+            match now.duration_since(*oldest) >= window {
+                true => self.queue_commands.pop_front(),
+                false => break,
+            };
+        }
+
+        if self.queue_commands.len() < limit.commands as usize {
+            return None;
+        }
+
+        // the window frees up again once the oldest command in it expires
+        let oldest = self.queue_commands.front()?;
+        window.checked_sub(now.duration_since(*oldest))
+    }
+
+    /// Records that the user ran the command, forgetting uses too old to matter anymore.
+    pub fn record(&mut self, user_id: UserId, command: &str, now: Instant) {
+        let max_cooldown = Duration::from_secs(MAX_COOLDOWN);
+        self.last_used
+            .retain(|_, last_used| now.duration_since(*last_used) < max_cooldown);
+        self.last_used.insert((user_id, command.to_string()), now);
+
+        if QUEUE_MODIFYING_COMMANDS.contains(&command) {
+            self.queue_commands.push_back(now);
+        }
+    }
+}
@@ -1,24 +1,134 @@
 use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
-use serenity::{model::id::GuildId, prelude::TypeMapKey};
+use serenity::{
+    model::id::{ChannelId, GuildId, RoleId, UserId},
+    prelude::TypeMapKey,
+};
 use std::{
     collections::{HashMap, HashSet},
     env,
+    fmt::Display,
     fs::{create_dir_all, rename, OpenOptions},
     io::{BufReader, BufWriter},
     path::Path,
 };
 
-use crate::errors::ParrotError;
+use crate::{
+    errors::ParrotError,
+    guild::{
+        filter::ContentFilter,
+        rate_limit::{QueueRateLimit, MAX_COOLDOWN},
+    },
+    messaging::i18n::tr,
+    messaging::messages::{POLICY_ADMIN, POLICY_DISABLED, POLICY_DJ, POLICY_EVERYONE},
+    utils::{compare_domains, parse_domains},
+};
 
 const DEFAULT_SETTINGS_PATH: &str = "data/settings";
 const DEFAULT_ALLOWED_DOMAINS: [&str; 2] = ["youtube.com", "youtu.be"];
+const DEFAULT_AUTOLEAVE_GRACE: u64 = 60;
+const DEFAULT_IDLE_TIMEOUT: u64 = 60 * 10;
+const DEFAULT_SKIP_VOTE_PERCENT: u8 = 50;
+const QUEUE_COMMANDS: [&str; 2] = ["play", "superplay"];
 
 lazy_static! {
     static ref SETTINGS_PATH: String =
         env::var("SETTINGS_PATH").unwrap_or(DEFAULT_SETTINGS_PATH.to_string());
 }
 
+/// Who may use a command, overriding its default behavior.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CommandPolicy {
+    Everyone,
+    Dj,
+    Admin,
+    Disabled,
+}
+
+impl CommandPolicy {
+    pub const ALL: [CommandPolicy; 4] = [
+        CommandPolicy::Everyone,
+        CommandPolicy::Dj,
+        CommandPolicy::Admin,
+        CommandPolicy::Disabled,
+    ];
+
+    pub fn id(&self) -> &'static str {
+        match self {
+            Self::Everyone => "everyone",
+            Self::Dj => "dj",
+            Self::Admin => "admin",
+            Self::Disabled => "disabled",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<CommandPolicy> {
+        Self::ALL.into_iter().find(|policy| policy.id() == id)
+    }
+
+    /// Admins count as DJs too, but nobody gets to use a disabled command.
+    pub fn allows(&self, is_dj: bool, is_admin: bool) -> bool {
+        match self {
+            Self::Everyone => true,
+            Self::Dj => is_dj || is_admin,
+            Self::Admin => is_admin,
+            Self::Disabled => false,
+        }
+    }
+}
+
+impl Display for CommandPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Everyone => f.write_str(tr(POLICY_EVERYONE)),
+            Self::Dj => f.write_str(tr(POLICY_DJ)),
+            Self::Admin => f.write_str(tr(POLICY_ADMIN)),
+            Self::Disabled => f.write_str(tr(POLICY_DISABLED)),
+        }
+    }
+}
+
+/// What a banned member is kept from doing.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BanScope {
+    All,
+    Queue,
+}
+
+impl BanScope {
+    pub fn from_id(id: &str) -> Option<BanScope> {
+        match id {
+            "all" => Some(Self::All),
+            "queue" => Some(Self::Queue),
+            _ => None,
+        }
+    }
+}
+
+/// A member kept from using the bot, until the given unix timestamp if any.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct MusicBan {
+    pub scope: BanScope,
+    #[serde(default)]
+    pub expires: Option<i64>,
+}
+
+impl MusicBan {
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires.is_some_and(|expires| expires <= now)
+    }
+
+    /// Queue bans only apply to the commands that add tracks.
+    pub fn applies_to(&self, command_name: &str) -> bool {
+        match self.scope {
+            BanScope::All => true,
+            BanScope::Queue => QUEUE_COMMANDS.contains(&command_name),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct GuildSettings {
     pub guild_id: GuildId,
@@ -28,6 +138,54 @@ pub struct GuildSettings {
     pub allowed_domains: HashSet<String>,
     #[serde(default)]
     pub banned_domains: HashSet<String>,
+    #[serde(default)]
+    pub autoleave: bool,
+    #[serde(default = "default_autoleave_grace")]
+    pub autoleave_grace: u64,
+    #[serde(default)]
+    pub pause_when_empty: bool,
+    #[serde(default = "default_idle_timeout")]
+    pub idle_timeout: u64,
+    #[serde(default = "default_idle_announce")]
+    pub idle_announce: bool,
+    #[serde(default)]
+    pub idle_channel: Option<ChannelId>,
+    #[serde(default)]
+    pub always_on_channel: Option<ChannelId>,
+    #[serde(default)]
+    pub always_on_playlist: Option<String>,
+    #[serde(default)]
+    pub stage_topic: bool,
+    #[serde(default)]
+    pub broadcast_status: bool,
+    #[serde(default)]
+    pub broadcast_nickname: bool,
+    #[serde(default)]
+    pub broadcast_presence: bool,
+    #[serde(default)]
+    pub dj_role: Option<RoleId>,
+    #[serde(default)]
+    pub command_policies: HashMap<String, CommandPolicy>,
+    #[serde(default = "default_skip_vote_percent")]
+    pub skip_vote_percent: u8,
+    #[serde(default)]
+    pub vote_percents: HashMap<String, u8>,
+    #[serde(default)]
+    pub allowed_text_channels: HashSet<ChannelId>,
+    #[serde(default)]
+    pub allowed_voice_channels: HashSet<ChannelId>,
+    #[serde(default)]
+    pub content_filter: ContentFilter,
+    #[serde(default)]
+    pub music_bans: HashMap<UserId, MusicBan>,
+    #[serde(default)]
+    pub audit_channel: Option<ChannelId>,
+    #[serde(default)]
+    pub command_cooldowns: HashMap<String, u64>,
+    #[serde(default)]
+    pub queue_rate_limit: Option<QueueRateLimit>,
+    #[serde(default)]
+    pub locale: Option<String>,
 }
 
 fn default_allowed_domains() -> HashSet<String> {
@@ -37,6 +195,29 @@ fn default_allowed_domains() -> HashSet<String> {
         .collect()
 }
 
+fn default_autoleave_grace() -> u64 {
+    DEFAULT_AUTOLEAVE_GRACE
+}
+
+fn default_idle_timeout() -> u64 {
+    DEFAULT_IDLE_TIMEOUT
+}
+
+fn default_idle_announce() -> bool {
+    true
+}
+
+fn default_skip_vote_percent() -> u8 {
+    DEFAULT_SKIP_VOTE_PERCENT
+}
+
+fn toggle_channel(channels: &mut HashSet<ChannelId>, channel_id: ChannelId) -> bool {
+    if channels.remove(&channel_id) {
+        return false;
+    }
+    channels.insert(channel_id)
+}
+
 impl GuildSettings {
     pub fn new(guild_id: GuildId) -> GuildSettings {
         let allowed_domains: HashSet<String> = DEFAULT_ALLOWED_DOMAINS
@@ -49,6 +230,30 @@ impl GuildSettings {
             autopause: false,
             allowed_domains,
             banned_domains: HashSet::new(),
+            autoleave: false,
+            autoleave_grace: DEFAULT_AUTOLEAVE_GRACE,
+            pause_when_empty: false,
+            idle_timeout: DEFAULT_IDLE_TIMEOUT,
+            idle_announce: default_idle_announce(),
+            idle_channel: None,
+            always_on_channel: None,
+            always_on_playlist: None,
+            stage_topic: false,
+            broadcast_status: false,
+            broadcast_nickname: false,
+            broadcast_presence: false,
+            dj_role: None,
+            command_policies: HashMap::new(),
+            skip_vote_percent: DEFAULT_SKIP_VOTE_PERCENT,
+            vote_percents: HashMap::new(),
+            allowed_text_channels: HashSet::new(),
+            allowed_voice_channels: HashSet::new(),
+            content_filter: ContentFilter::default(),
+            music_bans: HashMap::new(),
+            audit_channel: None,
+            command_cooldowns: HashMap::new(),
+            queue_rate_limit: None,
+            locale: None,
         }
     }
 
@@ -96,24 +301,153 @@ impl GuildSettings {
         self.autopause = !self.autopause;
     }
 
-    pub fn set_allowed_domains(&mut self, allowed_str: &str) {
-        let allowed = allowed_str
-            .split(';')
-            .filter(|s| !s.is_empty())
-            .map(|s| s.to_string())
-            .collect();
+    pub fn toggle_pause_when_empty(&mut self) {
+        self.pause_when_empty = !self.pause_when_empty;
+    }
 
-        self.allowed_domains = allowed;
+    pub fn toggle_stage_topic(&mut self) {
+        self.stage_topic = !self.stage_topic;
     }
 
-    pub fn set_banned_domains(&mut self, banned_str: &str) {
-        let banned = banned_str
-            .split(';')
-            .filter(|s| !s.is_empty())
-            .map(|s| s.to_string())
-            .collect();
+    /// Returns whether auto-leave is enabled along with its grace period in seconds.
+    pub fn autoleave_settings(&self) -> (bool, u64) {
+        (self.autoleave, self.autoleave_grace)
+    }
+
+    /// Toggles auto-leave, or enables it with a new grace period if one is given.
+    pub fn set_autoleave(&mut self, grace: Option<u64>) {
+        match grace {
+            Some(grace) => {
+                self.autoleave = true;
+                self.autoleave_grace = grace;
+            }
+            None => self.autoleave = !self.autoleave,
+        }
+    }
+
+    /// Sets how many seconds the bot may sit idle before leaving, where zero means never.
+    pub fn set_idle_timeout(&mut self, timeout: u64) {
+        self.idle_timeout = timeout;
+    }
+
+    /// Sets whether to announce idle departures and where, falling back to the channel
+    /// the bot was summoned from when no channel is given.
+    pub fn set_idle_announcements(&mut self, announce: bool, channel_id: Option<ChannelId>) {
+        self.idle_announce = announce;
+        self.idle_channel = channel_id;
+    }
+
+    /// Keeps the bot in the given voice channel around the clock, optionally looping a playlist.
+    pub fn enable_always_on(&mut self, channel_id: ChannelId, playlist: Option<String>) {
+        self.always_on_channel = Some(channel_id);
+        self.always_on_playlist = playlist;
+    }
+
+    pub fn disable_always_on(&mut self) {
+        self.always_on_channel = None;
+        self.always_on_playlist = None;
+    }
 
-        self.banned_domains = banned;
+    /// Sets the share of listeners it takes for a vote to pass, keyed by the vote's name,
+    /// and returns the share that was actually stored.
+    pub fn set_vote_percent(&mut self, vote: &str, percent: u8) -> u8 {
+        let percent = percent.clamp(1, 100);
+        match vote {
+            "skip" => self.skip_vote_percent = percent,
+            _ => {
+                self.vote_percents.insert(vote.to_string(), percent);
+            }
+        }
+        percent
+    }
+
+    /// Allows or disallows a text channel for commands, returning whether it's now allowed.
+    pub fn toggle_text_channel(&mut self, channel_id: ChannelId) -> bool {
+        toggle_channel(&mut self.allowed_text_channels, channel_id)
+    }
+
+    /// Allows or disallows a voice channel for the bot, returning whether it's now allowed.
+    pub fn toggle_voice_channel(&mut self, channel_id: ChannelId) -> bool {
+        toggle_channel(&mut self.allowed_voice_channels, channel_id)
+    }
+
+    /// Without any allowed text channels, commands work everywhere.
+    pub fn is_text_channel_allowed(&self, channel_id: ChannelId) -> bool {
+        self.allowed_text_channels.is_empty() || self.allowed_text_channels.contains(&channel_id)
+    }
+
+    /// Without any allowed voice channels, the bot may join any of them.
+    pub fn is_voice_channel_allowed(&self, channel_id: ChannelId) -> bool {
+        self.allowed_voice_channels.is_empty() || self.allowed_voice_channels.contains(&channel_id)
+    }
+
+    /// Sets how many seconds each member has to wait between uses of a command, zero removing it.
+    pub fn set_command_cooldown(&mut self, command: &str, seconds: u64) {
+        match seconds {
+            0 => self.command_cooldowns.remove(command),
+            _ => self
+                .command_cooldowns
+                .insert(command.to_string(), seconds.min(MAX_COOLDOWN)),
+        };
+    }
+
+    /// Limits the queue to be modified at most `commands` times per window, zero lifting the limit.
+    pub fn set_queue_rate_limit(&mut self, commands: u32, window: u64) {
+        self.queue_rate_limit = (commands > 0).then_some(QueueRateLimit {
+            commands,
+            window: window.clamp(1, MAX_COOLDOWN),
+        });
+    }
+
+    /// Bans a member, replacing any earlier ban, and forgets bans that ran out in the meantime.
+    pub fn ban_user(&mut self, user_id: UserId, ban: MusicBan, now: i64) {
+        self.music_bans.retain(|_, ban| !ban.is_expired(now));
+        self.music_bans.insert(user_id, ban);
+    }
+
+    /// Lifts a member's ban, returning whether they were banned at all.
+    pub fn unban_user(&mut self, user_id: UserId, now: i64) -> bool {
+        self.music_bans
+            .remove(&user_id)
+            .is_some_and(|ban| !ban.is_expired(now))
+    }
+
+    /// Returns the member's ban unless it has already run out.
+    pub fn get_ban(&self, user_id: UserId, now: i64) -> Option<MusicBan> {
+        self.music_bans
+            .get(&user_id)
+            .filter(|ban| !ban.is_expired(now))
+            .copied()
+    }
+
+    /// Overrides who may use a command, or restores its default behavior if `None`.
+    pub fn set_command_policy(&mut self, command: &str, policy: Option<CommandPolicy>) {
+        match policy {
+            Some(policy) => self.command_policies.insert(command.to_string(), policy),
+            None => self.command_policies.remove(command),
+        };
+    }
+
+    pub fn set_allowed_domains(&mut self, allowed_str: &str) -> Result<(), ParrotError> {
+        self.allowed_domains = parse_domains(allowed_str).map_err(ParrotError::InvalidDomains)?;
+        Ok(())
+    }
+
+    pub fn set_banned_domains(&mut self, banned_str: &str) -> Result<(), ParrotError> {
+        self.banned_domains = parse_domains(banned_str).map_err(ParrotError::InvalidDomains)?;
+        Ok(())
+    }
+
+    /// A host is refused if it falls under a banned domain or, when nothing is banned,
+    /// under none of the allowed ones.
+    pub fn is_domain_allowed(&self, host: &str) -> bool {
+        let matches_any =
+            |domains: &HashSet<String>| domains.iter().any(|d| compare_domains(d, host));
+
+        match self.banned_domains.is_empty() {
+            true => matches_any(&self.allowed_domains),
+            false => !matches_any(&self.banned_domains),
+        }
     }
 
     pub fn update_domains(&mut self) {
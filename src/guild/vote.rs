@@ -0,0 +1,37 @@
+use std::time::Duration;
+
+/// A queue change that listeners can vote on in servers without DJs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum VoteAction {
+    Stop,
+    Clear,
+    Shuffle,
+    Remove,
+}
+
+impl VoteAction {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Stop => "stop",
+            Self::Clear => "clear",
+            Self::Shuffle => "shuffle",
+            Self::Remove => "remove",
+        }
+    }
+
+    /// Wiping out everyone's tracks takes a clear majority, other changes only half the listeners.
+    pub fn default_percent(&self) -> u8 {
+        match self {
+            Self::Stop | Self::Clear => 66,
+            Self::Shuffle | Self::Remove => 50,
+        }
+    }
+
+    /// How long a vote stays open before it's discarded.
+    pub fn expiry(&self) -> Duration {
+        match self {
+            Self::Stop | Self::Clear => Duration::from_secs(120),
+            Self::Shuffle | Self::Remove => Duration::from_secs(60),
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use serenity::{
+    async_trait,
+    client::Context,
+    http::Http,
+    model::id::GuildId,
+    prelude::{Mutex, RwLock, TypeMap},
+};
+use songbird::{Call, Event, EventContext, EventHandler};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio_util::sync::CancellationToken;
+
+use crate::{
+    commands::{play::enqueue_playlist, summon::register_global_events},
+    errors::ParrotError,
+    guild::{cache::GuildCacheMap, settings::GuildSettingsMap},
+    handlers::stage::{become_speaker, is_stage_channel},
+    messaging::i18n::{tr, with_guild_locale},
+    messaging::messages::{FAIL_JOIN_CHANNEL, FAIL_VOICE_MANAGER},
+};
+
+pub const PLAYLIST_LOOP_INTERVAL: Duration = Duration::from_secs(5);
+const REJOIN_INITIAL_DELAY: Duration = Duration::from_secs(5);
+const REJOIN_MAX_DELAY: Duration = Duration::from_secs(60 * 5);
+
+/// Re-queues the 24/7 playlist whenever the queue of a guild in 24/7 mode runs dry.
+pub struct PlaylistLoopHandler {
+    pub http: Arc<Http>,
+    pub guild_id: GuildId,
+    pub call: Arc<Mutex<Call>>,
+    pub ctx_data: Arc<RwLock<TypeMap>>,
+    pub refilling: Arc<AtomicBool>,
+}
+
+#[async_trait]
+impl EventHandler for PlaylistLoopHandler {
+    async fn act(&self, _ctx: &EventContext<'_>) -> Option<Event> {
+        let (playlist, filter) = {
+            let data = self.ctx_data.read().await;
+            let settings = data.get::<GuildSettingsMap>().unwrap();
+
+            settings
+                .get(&self.guild_id)
+                .filter(|guild_settings| guild_settings.always_on_channel.is_some())
+                .and_then(|guild_settings| {
+                    let playlist = guild_settings.always_on_playlist.clone()?;
+                    Some((playlist, guild_settings.content_filter.clone()))
+                })
+        }?;
+
+        if !self.call.lock().await.queue().is_empty() {
+            return None;
+        }
+
+        // resolving a playlist takes longer than the interval between checks
+        if self.refilling.swap(true, Ordering::Relaxed) {
+            return None;
+        }
+
+        let http = self.http.clone();
+        let ctx_data = self.ctx_data.clone();
+        let call = self.call.clone();
+        let guild_id = self.guild_id;
+        let refilling = self.refilling.clone();
+        tokio::spawn(async move {
+            // the queue messages redrawn along the way are kept in the guild's language
+            let refill = enqueue_playlist(&http, &ctx_data, &call, guild_id, &playlist, &filter);
+            let queued = with_guild_locale(&ctx_data, guild_id, refill).await;
+            if queued == 0 {
+                eprintln!("[WARN] Failed to queue the 24/7 playlist {}", playlist);
+            }
+            refilling.store(false, Ordering::Relaxed);
+        });
+
+        None
+    }
+}
+
+/// Joins the 24/7 channel of a guild unless the bot is already there,
+/// returning whether the guild has 24/7 mode enabled at all.
+pub async fn join_always_on_channel(ctx: &Context, guild_id: GuildId) -> Result<bool, ParrotError> {
+    let channel_id = {
+        let data = ctx.data.read().await;
+        let settings = data.get::<GuildSettingsMap>().unwrap();
+
+        settings
+            .get(&guild_id)
+            .and_then(|guild_settings| guild_settings.always_on_channel)
+    };
+
+    let Some(channel_id) = channel_id else {
+        return Ok(false);
+    };
+
+    let manager = songbird::get(ctx)
+        .await
+        .ok_or(ParrotError::Other(FAIL_VOICE_MANAGER))?;
+
+    if let Some(call) = manager.get(guild_id) {
+        let handler = call.lock().await;
+        let current_channel = handler.current_channel().map(|channel| channel.0.get());
+
+        if handler.current_connection().is_some() && current_channel == Some(channel_id.get()) {
+            return Ok(true);
+        }
+    }
+
+    manager
+        .join(guild_id, channel_id)
+        .await
+        .map_err(|e| ParrotError::Dynamic(format!("{} {}", tr(FAIL_JOIN_CHANNEL), e)))?;
+
+    register_global_events(ctx, manager, guild_id).await;
+
+    let guild = ctx.cache.guild(guild_id).map(|guild| guild.clone());
+    if let Some(guild) = guild.filter(|guild| is_stage_channel(guild, channel_id)) {
+        become_speaker(ctx, &guild, channel_id).await?;
+    }
+
+    Ok(true)
+}
+
+/// Joins the 24/7 channel right away, falling back to retrying in the background.
+pub async fn ensure_always_on(ctx: &Context, guild_id: GuildId) {
+    if let Err(err) = join_always_on_channel(ctx, guild_id).await {
+        eprintln!(
+            "[WARN] Failed to join 24/7 channel in {}: {}",
+            guild_id, err
+        );
+        schedule_rejoin(ctx, guild_id).await;
+    }
+}
+
+/// Keeps trying to rejoin the 24/7 channel with an exponential backoff until it succeeds
+/// or 24/7 mode is disabled. Only one attempt loop runs per guild at a time.
+pub async fn schedule_rejoin(ctx: &Context, guild_id: GuildId) {
+    let mut data = ctx.data.write().await;
+
+    let always_on = data
+        .get::<GuildSettingsMap>()
+        .unwrap()
+        .get(&guild_id)
+        .is_some_and(|guild_settings| guild_settings.always_on_channel.is_some());
+
+    if !always_on {
+        return;
+    }
+
+    let cache_map = data.get_mut::<GuildCacheMap>().unwrap();
+    let cache = cache_map.entry(guild_id).or_default();
+
+    if cache.always_on_rejoin.is_some() {
+        return;
+    }
+
+    let token = CancellationToken::new();
+    cache.always_on_rejoin = Some(token.clone());
+    drop(data);
+
+    let ctx = ctx.clone();
+    tokio::spawn(async move {
+        let mut delay = REJOIN_INITIAL_DELAY;
+
+        loop {
+            tokio::select! {
+                _ = token.cancelled() => return,
+                _ = tokio::time::sleep(delay) => {}
+            }
+
+            match join_always_on_channel(&ctx, guild_id).await {
+                Ok(_) => break,
+                Err(err) => {
+                    eprintln!(
+                        "[WARN] Failed to rejoin 24/7 channel in {}: {}",
+                        guild_id, err
+                    );
+                    delay = (delay * 2).min(REJOIN_MAX_DELAY);
+                }
+            }
+        }
+
+        stop_rejoin(&ctx.data, guild_id).await;
+    });
+}
+
+pub async fn stop_rejoin(data: &Arc<RwLock<TypeMap>>, guild_id: GuildId) {
+    let mut data = data.write().await;
+    let cache_map = data.get_mut::<GuildCacheMap>().unwrap();
+
+    if let Some(token) = cache_map
+        .get_mut(&guild_id)
+        .and_then(|cache| cache.always_on_rejoin.take())
+    {
+        token.cancel();
+    }
+}
@@ -0,0 +1,175 @@
+use serenity::{
+    all::{CreateEmbed, CreateMessage},
+    client::Context,
+    model::{
+        id::{ChannelId, GuildId, UserId},
+        Timestamp,
+    },
+    prelude::Mentionable,
+};
+use songbird::tracks::TrackHandle;
+use std::{collections::HashSet, fmt::Write};
+
+use crate::{
+    commands::play::get_track_metadata,
+    guild::settings::GuildSettingsMap,
+    messaging::i18n::tr,
+    messaging::messages::{
+        AUDIT_MORE_TRACKS, AUDIT_NO_CHANGES, AUDIT_REMOVED_TRACKS, AUDIT_REORDERED,
+        AUDIT_SETTINGS_CHANGED, AUDIT_UNKNOWN_TRACK, AUDIT_USED,
+    },
+};
+
+/// Commands whose effect on the queue is posted to the audit log.
+pub const AUDITED_QUEUE_COMMANDS: [&str; 5] = ["clear", "remove", "shuffle", "skip", "stop"];
+
+const AUDIT_TRACKS_SHOWN: usize = 10;
+const AUDIT_TITLE_MAX_LEN: usize = 80;
+
+async fn get_audit_channel(ctx: &Context, guild_id: GuildId) -> Option<ChannelId> {
+    let data = ctx.data.read().await;
+    let settings = data.get::<GuildSettingsMap>().unwrap();
+    settings.get(&guild_id)?.audit_channel
+}
+
+/// Copies the queue ahead of an audited command, or `None` if the guild keeps no audit log.
+pub async fn snapshot_queue(ctx: &Context, guild_id: GuildId) -> Option<Vec<TrackHandle>> {
+    get_audit_channel(ctx, guild_id).await?;
+
+    let manager = songbird::get(ctx).await?;
+    let queue = match manager.get(guild_id) {
+        Some(call) => call.lock().await.queue().current_queue(),
+        None => vec![],
+    };
+    Some(queue)
+}
+
+/// Posts which tracks a command took out of the queue, or that it reordered it.
+pub async fn log_queue_change(
+    ctx: &Context,
+    guild_id: GuildId,
+    user_id: UserId,
+    command_name: &str,
+    before: Vec<TrackHandle>,
+) {
+    let after = match songbird::get(ctx).await.and_then(|m| m.get(guild_id)) {
+        Some(call) => call.lock().await.queue().current_queue(),
+        None => vec![],
+    };
+
+    let removed: Vec<String> = before
+        .iter()
+        .filter(|track| !after.iter().any(|other| other.uuid() == track.uuid()))
+        .map(|track| {
+            get_track_metadata(track)
+                .and_then(|metadata| metadata.title)
+                .unwrap_or_else(|| tr(AUDIT_UNKNOWN_TRACK).to_string())
+        })
+        .collect();
+
+    let reordered = removed.is_empty()
+        && before
+            .iter()
+            .zip(after.iter())
+            .any(|(old, new)| old.uuid() != new.uuid());
+
+    let embed = match (removed.is_empty(), reordered) {
+        (false, _) => create_entry_embed(user_id, command_name).field(
+            tr(AUDIT_REMOVED_TRACKS),
+            describe_tracks(&removed),
+            false,
+        ),
+        (true, true) => create_entry_embed(user_id, command_name).field(
+            tr(AUDIT_REORDERED),
+            format!("{}", after.len().saturating_sub(1)),
+            false,
+        ),
+        (true, false) => create_entry_embed(user_id, command_name).field(
+            tr(AUDIT_REMOVED_TRACKS),
+            tr(AUDIT_NO_CHANGES),
+            false,
+        ),
+    };
+
+    post_entry(ctx, guild_id, embed).await;
+}
+
+/// Posts the settings a command changed, one line per changed setting.
+pub async fn log_settings_change(
+    ctx: &Context,
+    guild_id: GuildId,
+    user_id: UserId,
+    command_name: &str,
+    changes: Vec<String>,
+) {
+    let changes = match changes.is_empty() {
+        true => tr(AUDIT_NO_CHANGES).to_string(),
+        false => changes.join("\n"),
+    };
+
+    let embed =
+        create_entry_embed(user_id, command_name).field(tr(AUDIT_SETTINGS_CHANGED), changes, false);
+    post_entry(ctx, guild_id, embed).await;
+}
+
+fn create_entry_embed(user_id: UserId, command_name: &str) -> CreateEmbed {
+    CreateEmbed::new()
+        .description(format!(
+            "{} {} `/{}`",
+            user_id.mention(),
+            tr(AUDIT_USED),
+            command_name
+        ))
+        .timestamp(Timestamp::now())
+}
+
+async fn post_entry(ctx: &Context, guild_id: GuildId, embed: CreateEmbed) {
+    let Some(channel_id) = get_audit_channel(ctx, guild_id).await else {
+        return;
+    };
+
+    let message = CreateMessage::new().embed(embed);
+    if let Err(e) = channel_id.send_message(&ctx.http, message).await {
+        eprintln!("[WARN] Failed to post to the audit log: {}", e);
+    }
+}
+
+/// Lists track titles, leaving out the ones that wouldn't fit in an embed field.
+pub fn describe_tracks(titles: &[String]) -> String {
+    let mut text = String::new();
+    for (i, title) in titles.iter().take(AUDIT_TRACKS_SHOWN).enumerate() {
+        let title: String = title.chars().take(AUDIT_TITLE_MAX_LEN).collect();
+        let _ = writeln!(text, "`{}.` {}", i + 1, title);
+    }
+
+    if titles.len() > AUDIT_TRACKS_SHOWN {
+        let remaining = titles.len() - AUDIT_TRACKS_SHOWN;
+        let _ = write!(text, "… {} {}", remaining, tr(AUDIT_MORE_TRACKS));
+    }
+    text.trim_end().to_string()
+}
+
+/// Describes what was added to and removed from a set of values, if anything.
+pub fn diff_values(
+    label: &str,
+    before: &HashSet<String>,
+    after: &HashSet<String>,
+) -> Option<String> {
+    let mut added: Vec<_> = after
+        .difference(before)
+        .map(|v| format!("+`{}`", v))
+        .collect();
+    let mut removed: Vec<_> = before
+        .difference(after)
+        .map(|v| format!("-`{}`", v))
+        .collect();
+
+    if added.is_empty() && removed.is_empty() {
+        return None;
+    }
+
+    added.sort();
+    removed.sort();
+    added.extend(removed);
+    Some(format!("**{}**: {}", label, added.join(", ")))
+}
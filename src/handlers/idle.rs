@@ -1,18 +1,32 @@
-use serenity::{all::CommandInteraction, async_trait, http::Http};
+use serenity::{
+    async_trait,
+    http::Http,
+    model::id::GuildId,
+    prelude::{RwLock, TypeMap},
+};
 use songbird::{tracks::PlayMode, Event, EventContext, EventHandler, Songbird};
 use std::sync::{
-    atomic::{AtomicUsize, Ordering},
+    atomic::{AtomicU64, Ordering},
     Arc,
 };
 
-use crate::messaging::messages::IDLE_ALERT;
+use crate::{
+    guild::{
+        cache::GuildCacheMap,
+        settings::{GuildSettings, GuildSettingsMap},
+    },
+    messaging::{
+        i18n::{tr, with_guild_locale},
+        messages::IDLE_ALERT,
+    },
+};
 
 pub struct IdleHandler {
     pub http: Arc<Http>,
     pub manager: Arc<Songbird>,
-    pub interaction: CommandInteraction,
-    pub limit: usize,
-    pub count: Arc<AtomicUsize>,
+    pub guild_id: GuildId,
+    pub ctx_data: Arc<RwLock<TypeMap>>,
+    pub count: Arc<AtomicU64>,
 }
 
 #[async_trait]
@@ -34,18 +48,45 @@ impl EventHandler for IdleHandler {
             return None;
         }
 
-        if self.count.fetch_add(1, Ordering::Relaxed) >= self.limit {
-            let guild_id = self.interaction.guild_id?;
-
-            if self.manager.remove(guild_id).await.is_ok() {
-                if let Err(e) = self
-                    .interaction
-                    .channel_id
-                    .say(&self.http, IDLE_ALERT)
-                    .await
-                {
-                    eprintln!("[WARN] Failed to send idle alert: {}", e);
-                }
+        // settings are read on every tick so that changes apply to the ongoing session
+        let (limit, announce, channel_id, always_on) = {
+            let data = self.ctx_data.read().await;
+            let settings = data.get::<GuildSettingsMap>().unwrap();
+            let default_settings = GuildSettings::new(self.guild_id);
+            let guild_settings = settings.get(&self.guild_id).unwrap_or(&default_settings);
+
+            let summon_channel = data
+                .get::<GuildCacheMap>()
+                .unwrap()
+                .get(&self.guild_id)
+                .and_then(|cache| cache.text_channel);
+
+            (
+                guild_settings.idle_timeout,
+                guild_settings.idle_announce,
+                guild_settings.idle_channel.or(summon_channel),
+                guild_settings.always_on_channel.is_some(),
+            )
+        };
+
+        // a timeout of zero means the bot never leaves on its own, nor does it in 24/7 mode
+        if limit == 0 || always_on {
+            self.count.store(0, Ordering::Relaxed);
+            return None;
+        }
+
+        if self.count.fetch_add(1, Ordering::Relaxed) < limit {
+            return None;
+        }
+
+        if self.manager.remove(self.guild_id).await.is_err() || !announce {
+            return None;
+        }
+
+        if let Some(channel_id) = channel_id {
+            let alert = with_guild_locale(&self.ctx_data, self.guild_id, async { tr(IDLE_ALERT) });
+            if let Err(e) = channel_id.say(&self.http, alert.await).await {
+                eprintln!("[WARN] Failed to send idle alert: {}", e);
             }
         }
 
@@ -1,6 +1,12 @@
+pub mod always_on;
+pub mod audit_log;
 pub mod idle;
+pub mod now_playing;
+pub mod reconnect;
 pub mod serenity;
+pub mod stage;
 pub mod track_end;
+pub mod voice_state;
 
 pub use self::idle::IdleHandler;
 pub use self::serenity::SerenityHandler;
@@ -0,0 +1,131 @@
+use serde_json::json;
+use serenity::{
+    async_trait,
+    client::Context,
+    gateway::ActivityData,
+    model::id::{ChannelId, GuildId},
+    prelude::Mutex,
+};
+use songbird::{Call, Event, EventContext, EventHandler};
+use std::sync::Arc;
+
+use crate::{
+    commands::play::get_track_metadata,
+    guild::{cache::GuildCacheMap, settings::GuildSettingsMap},
+    handlers::stage::update_stage_topic,
+};
+
+const DEFAULT_ACTIVITY: &str = "/play";
+const NICKNAME_PREFIX: &str = "🎶 ";
+const NICKNAME_MAX_LEN: usize = 32;
+const VOICE_STATUS_MAX_LEN: usize = 500;
+
+/// Broadcasts the track that just started playing outside of the queue messages,
+/// and clears it again once the queue runs out.
+pub struct NowPlayingHandler {
+    pub ctx: Context,
+    pub guild_id: GuildId,
+    pub call: Arc<Mutex<Call>>,
+}
+
+#[async_trait]
+impl EventHandler for NowPlayingHandler {
+    async fn act(&self, ctx: &EventContext<'_>) -> Option<Event> {
+        let handler = self.call.lock().await;
+        let channel_id = handler.current_channel()?;
+        let track = handler.queue().current();
+        drop(handler);
+
+        // the queue might not have moved past the track that just ended yet
+        let ended = match ctx {
+            EventContext::Track(track_list) => track_list
+                .iter()
+                .filter(|(state, _)| state.playing.is_done())
+                .map(|(_, handle)| handle.uuid())
+                .collect(),
+            _ => vec![],
+        };
+
+        let title = track
+            .filter(|track| !ended.contains(&track.uuid()))
+            .and_then(|track| get_track_metadata(&track))
+            .and_then(|metadata| metadata.title);
+
+        broadcast_now_playing(&self.ctx, self.guild_id, channel_id.0.into(), title).await;
+        None
+    }
+}
+
+pub fn default_activity() -> ActivityData {
+    ActivityData::listening(DEFAULT_ACTIVITY)
+}
+
+/// Shows the given track title wherever the guild opted into it, or clears it if `None`.
+pub async fn broadcast_now_playing(
+    ctx: &Context,
+    guild_id: GuildId,
+    channel_id: ChannelId,
+    title: Option<String>,
+) {
+    let mut data = ctx.data.write().await;
+    let cache_map = data.get_mut::<GuildCacheMap>().unwrap();
+    let cache = cache_map.entry(guild_id).or_default();
+
+    // pausing and resuming fires the same events, which shouldn't hit the API every time
+    if cache.now_playing == title {
+        return;
+    }
+    cache.now_playing = title.clone();
+
+    let Some(guild_settings) = data.get::<GuildSettingsMap>().unwrap().get(&guild_id) else {
+        return;
+    };
+    let stage_topic = guild_settings.stage_topic;
+    let voice_status = guild_settings.broadcast_status;
+    let nickname = guild_settings.broadcast_nickname;
+    let presence = guild_settings.broadcast_presence;
+    drop(data);
+
+    if let (true, Some(title)) = (stage_topic, &title) {
+        update_stage_topic(ctx, guild_id, channel_id, title).await;
+    }
+
+    if voice_status {
+        let status: String = title
+            .as_deref()
+            .unwrap_or_default()
+            .chars()
+            .take(VOICE_STATUS_MAX_LEN)
+            .collect();
+
+        if let Err(e) = ctx
+            .http
+            .edit_voice_status(channel_id, &json!({ "status": status }), None)
+            .await
+        {
+            eprintln!("[WARN] Failed to update voice channel status: {}", e);
+        }
+    }
+
+    if nickname {
+        let nickname = title.as_ref().map(|title| {
+            format!("{}{}", NICKNAME_PREFIX, title)
+                .chars()
+                .take(NICKNAME_MAX_LEN)
+                .collect::<String>()
+        });
+
+        if let Err(e) = guild_id.edit_nickname(&ctx.http, nickname.as_deref()).await {
+            eprintln!("[WARN] Failed to update nickname: {}", e);
+        }
+    }
+
+    // presence is shared by every guild, so it only makes sense with a single one
+    if presence && ctx.cache.guild_count() == 1 {
+        let activity = match title {
+            Some(title) => ActivityData::listening(title),
+            None => default_activity(),
+        };
+        ctx.set_activity(Some(activity));
+    }
+}
@@ -0,0 +1,239 @@
+use serenity::{
+    async_trait,
+    client::Context,
+    model::id::GuildId,
+    prelude::{Mutex, RwLock, TypeMap},
+};
+use songbird::{
+    events::context_data::{DisconnectKind, DisconnectReason},
+    id::ChannelId,
+    model::CloseCode,
+    tracks::{PlayMode, TrackHandle},
+    Call, Event, EventContext, EventHandler,
+};
+use std::{sync::Arc, time::Duration};
+
+use crate::{
+    errors::ParrotError,
+    guild::cache::GuildCacheMap,
+    handlers::always_on::schedule_rejoin,
+    messaging::{i18n::tr, messages::FAIL_JOIN_CHANNEL},
+};
+
+const RECONNECT_ATTEMPTS: u32 = 5;
+const RECONNECT_INITIAL_DELAY: Duration = Duration::from_secs(1);
+const MOVE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Reconnects after the voice connection drops for reasons other than the bot being
+/// kicked or told to leave, picking the current track back up where it left off.
+pub struct DriverDisconnectHandler {
+    pub ctx: Context,
+    pub guild_id: GuildId,
+    pub call: Arc<Mutex<Call>>,
+}
+
+struct PlaybackSnapshot {
+    track: TrackHandle,
+    position: Duration,
+    was_playing: bool,
+}
+
+#[async_trait]
+impl EventHandler for DriverDisconnectHandler {
+    async fn act(&self, ctx: &EventContext<'_>) -> Option<Event> {
+        let EventContext::DriverDisconnect(data) = ctx else {
+            return None;
+        };
+
+        if !is_transient_disconnect(data.kind, data.reason) {
+            return None;
+        }
+
+        let channel_id = data.channel_id?;
+        if !begin_reconnect(&self.ctx.data, self.guild_id).await {
+            return None;
+        }
+
+        let ctx = self.ctx.clone();
+        let call = self.call.clone();
+        let guild_id = self.guild_id;
+        tokio::spawn(async move { reconnect(ctx, guild_id, call, channel_id).await });
+
+        None
+    }
+}
+
+/// Tells a dropped connection worth recovering from apart from the bot leaving on purpose,
+/// being kicked or moved, or failing to connect in the first place.
+pub fn is_transient_disconnect(kind: DisconnectKind, reason: Option<DisconnectReason>) -> bool {
+    if kind == DisconnectKind::Connect {
+        return false;
+    }
+
+    !matches!(
+        reason,
+        Some(DisconnectReason::Requested)
+            | Some(DisconnectReason::AttemptDiscarded)
+            | Some(DisconnectReason::WsClosed(Some(CloseCode::Disconnected)))
+    )
+}
+
+/// Returns whether the voice connection of a guild is currently being re-established.
+pub async fn is_reconnecting(data: &Arc<RwLock<TypeMap>>, guild_id: GuildId) -> bool {
+    let data = data.read().await;
+    let cache_map = data.get::<GuildCacheMap>().unwrap();
+
+    cache_map
+        .get(&guild_id)
+        .is_some_and(|cache| cache.reconnecting)
+}
+
+async fn begin_reconnect(data: &Arc<RwLock<TypeMap>>, guild_id: GuildId) -> bool {
+    let mut data = data.write().await;
+    let cache_map = data.get_mut::<GuildCacheMap>().unwrap();
+    let cache = cache_map.entry(guild_id).or_default();
+
+    !std::mem::replace(&mut cache.reconnecting, true)
+}
+
+async fn finish_reconnect(data: &Arc<RwLock<TypeMap>>, guild_id: GuildId) {
+    let mut data = data.write().await;
+    let cache_map = data.get_mut::<GuildCacheMap>().unwrap();
+
+    if let Some(cache) = cache_map.get_mut(&guild_id) {
+        cache.reconnecting = false;
+    }
+}
+
+async fn reconnect(ctx: Context, guild_id: GuildId, call: Arc<Mutex<Call>>, channel_id: ChannelId) {
+    let snapshot = pause_current_track(&call).await;
+
+    let mut delay = RECONNECT_INITIAL_DELAY;
+    let mut connected = false;
+
+    for attempt in 1..=RECONNECT_ATTEMPTS {
+        tokio::time::sleep(delay).await;
+
+        match rejoin(&call, channel_id).await {
+            Ok(()) => {
+                connected = true;
+                break;
+            }
+            Err(err) => eprintln!(
+                "[WARN] Reconnection attempt {} in {} failed: {}",
+                attempt, guild_id, err
+            ),
+        }
+        delay *= 2;
+    }
+
+    finish_reconnect(&ctx.data, guild_id).await;
+
+    if connected {
+        return resume_track(&call, snapshot).await;
+    }
+
+    // out of luck, so clean up the way a regular disconnect would
+    if let Some(manager) = songbird::get(&ctx).await {
+        manager.remove(guild_id).await.ok();
+    }
+    schedule_rejoin(&ctx, guild_id).await;
+}
+
+/// Switches the call over to another voice channel, keeping the queue, the registered
+/// events and the position of the current track.
+pub async fn move_call(call: &Arc<Mutex<Call>>, channel_id: ChannelId) -> Result<(), ParrotError> {
+    let snapshot = pause_current_track(call).await;
+
+    let join = { call.lock().await.join(channel_id).await }
+        .map_err(|e| ParrotError::Dynamic(format!("{} {}", tr(FAIL_JOIN_CHANNEL), e)));
+
+    let result = match join {
+        Ok(join) => join
+            .await
+            .map_err(|e| ParrotError::Dynamic(format!("{} {}", tr(FAIL_JOIN_CHANNEL), e))),
+        Err(err) => Err(err),
+    };
+
+    // pick the track back up either way, rather than leaving it paused for good
+    resume_track(call, snapshot).await;
+    result
+}
+
+/// Songbird follows the bot when a moderator drags it elsewhere, but only once Discord
+/// hands out the new voice server. Forces a reconnect should that never happen.
+pub async fn follow_move(ctx: &Context, guild_id: GuildId, channel_id: ChannelId) {
+    let ctx = ctx.clone();
+
+    tokio::spawn(async move {
+        tokio::time::sleep(MOVE_TIMEOUT).await;
+
+        let Some(call) = songbird::get(&ctx)
+            .await
+            .and_then(|manager| manager.get(guild_id))
+        else {
+            return;
+        };
+
+        let handler = call.lock().await;
+        let stuck =
+            handler.current_connection().is_none() && handler.current_channel() == Some(channel_id);
+        drop(handler);
+
+        if stuck && begin_reconnect(&ctx.data, guild_id).await {
+            reconnect(ctx, guild_id, call, channel_id).await;
+        }
+    });
+}
+
+async fn rejoin(call: &Arc<Mutex<Call>>, channel_id: ChannelId) -> Result<(), ParrotError> {
+    // joining the channel the call thinks it's still in does nothing, so leave first;
+    // the driver keeps its tracks in the meantime
+    let join = {
+        let mut handler = call.lock().await;
+        handler.leave().await.ok();
+        handler.join(channel_id).await
+    }
+    .map_err(|e| ParrotError::Dynamic(format!("{} {}", tr(FAIL_JOIN_CHANNEL), e)))?;
+
+    // the call must be unlocked while the driver connects
+    join.await
+        .map_err(|e| ParrotError::Dynamic(format!("{} {}", tr(FAIL_JOIN_CHANNEL), e)))
+}
+
+async fn pause_current_track(call: &Arc<Mutex<Call>>) -> Option<PlaybackSnapshot> {
+    let track = call.lock().await.queue().current()?;
+    let info = track.get_info().await.ok()?;
+    let was_playing = matches!(info.playing, PlayMode::Play);
+
+    track.pause().ok();
+
+    Some(PlaybackSnapshot {
+        track,
+        position: info.position,
+        was_playing,
+    })
+}
+
+async fn resume_track(call: &Arc<Mutex<Call>>, snapshot: Option<PlaybackSnapshot>) {
+    let Some(snapshot) = snapshot else {
+        return;
+    };
+
+    // the queue might have moved on if a track ended right as the connection dropped
+    let current = call.lock().await.queue().current();
+    if current.map(|track| track.uuid()) != Some(snapshot.track.uuid()) {
+        return;
+    }
+
+    snapshot
+        .track
+        .seek(snapshot.position)
+        .result_async()
+        .await
+        .ok();
+
+    if snapshot.was_playing {
+        snapshot.track.play().ok();
+    }
+}
@@ -1,188 +1,740 @@
 use crate::{
     commands::{
-        autopause::*, clear::*, leave::*, manage_sources::*, now_playing::*, pause::*, play::*,
-        queue::*, remove::*, repeat::*, resume::*, seek::*, shuffle::*, skip::*, stop::*,
-        summon::*, version::*, voteskip::*,
+        always_on::*, audit_log::*, autoleave::*, autopause::*, broadcast::*, cache::*, cancel::*,
+        channels::*, clear::*, cooldown::*, djrole::*, emptypause::*, filter::*, idle::*,
+        language::*, leave::*, manage_sources::*, musicban::*, now_playing::*, pause::*,
+        permissions::*, play::*, queue::*, remove::*, repeat::*, resume::*, seek::*, shuffle::*,
+        skip::*, stagetopic::*, stop::*, summon::*, version::*, vote::*, votepercent::*,
+        voteskip::*,
     },
-    connection::{check_voice_connections, Connection},
-    errors::ParrotError,
-    guild::settings::{GuildSettings, GuildSettingsMap},
-    handlers::track_end::update_queue_messages,
-    sources::spotify::{Spotify, SPOTIFY},
-    utils::create_response_text,
+    connection::{check_voice_connections, get_listeners, get_voice_channel_for_user, Connection},
+    errors::{verify, ParrotError},
+    guild::{
+        cache::GuildCacheMap,
+        rate_limit::{MAX_COOLDOWN, QUEUE_MODIFYING_COMMANDS},
+        settings::{CommandPolicy, GuildSettings, GuildSettingsMap},
+    },
+    handlers::{
+        always_on::{ensure_always_on, schedule_rejoin},
+        audit_log::{log_queue_change, snapshot_queue, AUDITED_QUEUE_COMMANDS},
+        now_playing::{broadcast_now_playing, default_activity},
+        reconnect::{follow_move, is_reconnecting},
+        track_end::update_queue_messages,
+        voice_state::handle_listener_change,
+    },
+    messaging::i18n::{
+        get_guild_locale, localized_command, localized_option, with_guild_locale, with_locale,
+        LANGUAGES,
+    },
+    messaging::messages::{FAIL_GUILD_NOT_CACHED, FAIL_GUILD_ONLY, FAIL_VOICE_MANAGER},
+    utils::{create_response_text, mention_all},
 };
 use serenity::{
-    all::{CommandOptionType, CreateCommand, CreateCommandOption, EditMember},
+    all::{
+        ChannelType, CommandOptionType, ComponentInteraction, CreateCommandOption, CreateEmbed,
+        CreateInteractionResponse, CreateInteractionResponseMessage, EditMember, Member,
+        Permissions,
+    },
     async_trait,
     client::{Context, EventHandler},
-    gateway::ActivityData,
+    gateway::ShardStageUpdateEvent,
     model::{
-        application::Command, application::Interaction, gateway::Ready, id::GuildId,
+        application::Command,
+        application::Interaction,
+        gateway::Ready,
+        guild::Guild,
+        id::{ChannelId, GuildId, RoleId, UserId},
         voice::VoiceState,
+        Timestamp,
     },
     prelude::Mentionable,
 };
+use songbird::tracks::TrackHandle;
+use std::{
+    collections::hash_map::Entry,
+    time::{Duration, Instant},
+};
 
 pub struct SerenityHandler;
 
+/// Commands that affect other people's tracks and are therefore reserved for DJs.
+const DJ_COMMANDS: [&str; 4] = ["clear", "remove", "shuffle", "stop"];
+
+/// Returns the tracks a destructive command would act upon.
+fn affected_tracks<'a>(
+    command: &serenity::all::CommandInteraction,
+    queue: &'a [TrackHandle],
+) -> &'a [TrackHandle] {
+    let get_option = |name: &str| {
+        command
+            .data
+            .options
+            .iter()
+            .find(|opt| opt.name == name)
+            .and_then(|opt| opt.value.as_i64())
+            .map(|value| value.max(1) as usize)
+    };
+
+    match command.data.name.as_str() {
+        "remove" => {
+            let index = get_option("index").unwrap_or(1);
+            let until = get_option("until").unwrap_or(index);
+            let end = (until + 1).min(queue.len());
+            &queue[index.min(end)..end]
+        }
+        "stop" => queue,
+        // the track that's playing is left alone
+        _ => queue.get(1..).unwrap_or_default(),
+    }
+}
+
+fn has_role(member: Option<&Member>, role_id: RoleId) -> bool {
+    member.is_some_and(|member| member.roles.contains(&role_id))
+}
+
+fn is_admin(member: Option<&Member>) -> bool {
+    member
+        .and_then(|member| member.permissions)
+        .is_some_and(|permissions| permissions.administrator())
+}
+
+fn playlist_position_option(name: &str, description: &'static str) -> CreateCommandOption {
+    localized_option(CommandOptionType::Integer, name, description)
+        .required(false)
+        .min_int_value(1)
+}
+
 #[async_trait]
 impl EventHandler for SerenityHandler {
     async fn ready(&self, ctx: Context, ready: Ready) {
-        println!("🦜 {} is connected!", ready.user.name);
+        match ready.shard {
+            Some(shard) => println!(
+                "🦜 {} is connected on shard {}/{} with {} guilds!",
+                ready.user.name,
+                shard.id.0 + 1,
+                shard.total,
+                ready.guilds.len()
+            ),
+            None => println!("🦜 {} is connected!", ready.user.name),
+        }
 
         // sets parrot activity status message to /play
-        let activity = ActivityData::listening("/play");
-        ctx.set_activity(Some(activity));
-
-        // attempts to authenticate to spotify
-        *SPOTIFY.lock().await = Spotify::auth().await;
+        ctx.set_activity(Some(default_activity()));
 
-        // creates the global application commands
-        self.create_commands(&ctx).await;
+        // every shard goes through ready, but global commands only need creating once
+        if ready.shard.is_none_or(|shard| shard.id.0 == 0) {
+            self.create_commands(&ctx).await;
+        }
 
         // loads serialized guild settings
         self.load_guilds_settings(&ctx, &ready).await;
     }
 
+    async fn guild_create(&self, ctx: Context, guild: Guild, _is_new: Option<bool>) {
+        let mut data = ctx.data.write().await;
+        let settings = data.get_mut::<GuildSettingsMap>().unwrap();
+
+        // guilds joined after startup haven't had their settings loaded yet
+        if let Entry::Vacant(entry) = settings.entry(guild.id) {
+            let guild_settings = entry.insert(GuildSettings::new(guild.id));
+            if let Err(err) = guild_settings.load_if_exists() {
+                eprintln!(
+                    "[ERROR] Failed to load guild {} settings due to {}",
+                    guild.id, err
+                );
+            }
+        }
+        drop(data);
+
+        // every guild is created at startup too, so this is also where the bot heads back
+        // to the channels of guilds in 24/7 mode
+        ensure_always_on(&ctx, guild.id).await;
+    }
+
+    async fn shards_ready(&self, _ctx: Context, total_shards: u32) {
+        println!("[INFO] All {} shards are ready", total_shards);
+    }
+
+    async fn shard_stage_update(&self, _ctx: Context, event: ShardStageUpdateEvent) {
+        println!(
+            "[INFO] Shard {} went from {} to {}",
+            event.shard_id, event.old, event.new
+        );
+    }
+
     async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
-        let Interaction::Command(mut command) = interaction else {
+        match interaction {
+            Interaction::Command(mut command) => {
+                let locale = self
+                    .get_locale(&ctx, command.guild_id, &command.locale)
+                    .await;
+                with_locale(Some(&locale), async {
+                    if let Err(err) = self.run_command(&ctx, &mut command).await {
+                        self.handle_error(&ctx, &mut command, err).await
+                    }
+                })
+                .await
+            }
+            // other buttons and menus are handled by the collectors of their own messages
+            Interaction::Component(component) if component.data.custom_id == VOTESKIP_BUTTON_ID => {
+                let locale = self
+                    .get_locale(&ctx, component.guild_id, &component.locale)
+                    .await;
+                with_locale(Some(&locale), self.vote_from_button(&ctx, &component)).await
+            }
+            _ => {}
+        }
+    }
+
+    async fn voice_state_update(&self, ctx: Context, old: Option<VoiceState>, new: VoiceState) {
+        let Some(guild_id) = new.guild_id else {
             return;
         };
 
-        if let Err(err) = self.run_command(&ctx, &mut command).await {
-            self.handle_error(&ctx, &mut command, err).await
-        }
-    }
+        // someone might have left or joined the bot's voice channel
+        handle_listener_change(&ctx, guild_id).await;
+        forget_absent_votes(&ctx, guild_id).await;
 
-    async fn voice_state_update(&self, ctx: Context, _old: Option<VoiceState>, new: VoiceState) {
-        // do nothing if this is a voice update event for a user, not a bot
+        // do nothing else if this is a voice update event for a user, not a bot
         if new.user_id != ctx.cache.current_user().id {
             return;
         }
 
-        if new.channel_id.is_some() {
+        if let Some(channel_id) = new.channel_id {
+            // a moderator dragged the bot elsewhere, which isn't a reason to drop the queue
+            let old_channel_id = old.and_then(|old| old.channel_id);
+            if old_channel_id.is_some_and(|old_channel_id| old_channel_id != channel_id) {
+                follow_move(&ctx, guild_id, channel_id.into()).await;
+            }
+
             return self.self_deafen(&ctx, new.guild_id, new).await;
         }
 
-        let Some(manager) = songbird::get(&ctx).await else {
+        // leaving is part of re-establishing a dropped connection, so keep the queue around
+        if is_reconnecting(&ctx.data, guild_id).await {
             return;
-        };
-        let Some(guild_id) = new.guild_id else {
+        }
+
+        let Some(manager) = songbird::get(&ctx).await else {
             return;
         };
 
+        // whatever was being broadcast isn't playing anymore
+        if let Some(channel_id) = old.and_then(|old| old.channel_id) {
+            broadcast_now_playing(&ctx, guild_id, channel_id, None).await;
+        }
+
+        cancel_imports(&ctx.data, guild_id).await;
         if manager.get(guild_id).is_some() {
             manager.remove(guild_id).await.ok();
         }
 
-        update_queue_messages(&ctx.http, &ctx.data, &[], guild_id).await;
+        let update = update_queue_messages(&ctx.http, &ctx.data, &[], guild_id);
+        with_guild_locale(&ctx.data, guild_id, update).await;
+
+        // guilds in 24/7 mode should get the bot back after a disconnect
+        schedule_rejoin(&ctx, guild_id).await;
     }
 }
 
 impl SerenityHandler {
     async fn create_commands(&self, ctx: &Context) -> Vec<Command> {
         let commands = vec![
-            CreateCommand::new("autopause")
-                .description("Toggles whether to pause after a song ends"),
-            CreateCommand::new("clear").description("Clears the queue"),
-            CreateCommand::new("leave")
-                .description("Leave the voice channel the bot is connected to"),
-            CreateCommand::new("managesources")
-                .description("Manage streaming from different sources"),
-            CreateCommand::new("np").description("Displays information about the current track"),
-            CreateCommand::new("pause").description("Pauses the current track"),
-            CreateCommand::new("play")
-                .description("Add a track to the queue")
-                .add_option(
-                    CreateCommandOption::new(
+            localized_command(
+                "alwayson",
+                "Keep the bot in a voice channel around the clock",
+            )
+            .default_member_permissions(Permissions::ADMINISTRATOR)
+            .add_option(
+                localized_option(
+                    CommandOptionType::SubCommand,
+                    "enable",
+                    "Park the bot in a voice channel permanently",
+                )
+                .add_sub_option(
+                    localized_option(
+                        CommandOptionType::Channel,
+                        "channel",
+                        "The voice channel to stay in",
+                    )
+                    .channel_types(vec![ChannelType::Voice, ChannelType::Stage])
+                    .required(true),
+                )
+                .add_sub_option(
+                    localized_option(
                         CommandOptionType::String,
-                        "query",
-                        "The media to play",
+                        "playlist",
+                        "A playlist to keep looping while the queue is empty",
                     )
+                    .required(false),
+                ),
+            )
+            .add_option(localized_option(
+                CommandOptionType::SubCommand,
+                "disable",
+                "Let the bot come and go again",
+            )),
+            localized_command(
+                "auditlog",
+                "Log who ran destructive or admin commands to a channel",
+            )
+            .default_member_permissions(Permissions::ADMINISTRATOR)
+            .add_option(
+                localized_option(
+                    CommandOptionType::Channel,
+                    "channel",
+                    "The channel to log to, leave empty to stop logging",
+                )
+                .channel_types(vec![ChannelType::Text, ChannelType::News])
+                .required(false),
+            ),
+            localized_command(
+                "autoleave",
+                "Toggles whether to leave once everyone else has left the voice channel",
+            )
+            .default_member_permissions(Permissions::ADMINISTRATOR)
+            .add_option(
+                localized_option(
+                    CommandOptionType::Integer,
+                    "grace",
+                    "Seconds to wait for someone to come back before leaving",
+                )
+                .required(false)
+                .min_int_value(0),
+            ),
+            localized_command("autopause", "Toggles whether to pause after a song ends"),
+            localized_command(
+                "broadcast",
+                "Choose where to show the current track besides the queue",
+            )
+            .default_member_permissions(Permissions::ADMINISTRATOR)
+            .add_option(
+                localized_option(
+                    CommandOptionType::Boolean,
+                    "status",
+                    "Show the current track as the voice channel status",
+                )
+                .required(false),
+            )
+            .add_option(
+                localized_option(
+                    CommandOptionType::Boolean,
+                    "nickname",
+                    "Show the current track in my nickname",
+                )
+                .required(false),
+            )
+            .add_option(
+                localized_option(
+                    CommandOptionType::Boolean,
+                    "presence",
+                    "Show the current track in my activity, if I'm only in this server",
+                )
+                .required(false),
+            ),
+            localized_command("cache", "Displays statistics about the metadata cache")
+                .default_member_permissions(Permissions::ADMINISTRATOR),
+            localized_command(
+                "cancel",
+                "Cancels the playlist imports that are still in progress",
+            ),
+            localized_command(
+                "channels",
+                "Choose where the bot accepts commands and which channels it joins",
+            )
+            .default_member_permissions(Permissions::ADMINISTRATOR)
+            .add_option(
+                localized_option(
+                    CommandOptionType::SubCommand,
+                    "text",
+                    "Allow or disallow commands in a text channel",
+                )
+                .add_sub_option(
+                    localized_option(
+                        CommandOptionType::Channel,
+                        "channel",
+                        "The text channel to toggle",
+                    )
+                    .channel_types(vec![ChannelType::Text, ChannelType::News])
+                    .required(true),
+                ),
+            )
+            .add_option(
+                localized_option(
+                    CommandOptionType::SubCommand,
+                    "voice",
+                    "Allow or disallow the bot to join a voice channel",
+                )
+                .add_sub_option(
+                    localized_option(
+                        CommandOptionType::Channel,
+                        "channel",
+                        "The voice channel to toggle",
+                    )
+                    .channel_types(vec![ChannelType::Voice, ChannelType::Stage])
                     .required(true),
                 ),
-            CreateCommand::new("superplay")
-                .description("Add a track to the queue in a special way")
+            )
+            .add_option(localized_option(
+                CommandOptionType::SubCommand,
+                "reset",
+                "Allow every channel again",
+            )),
+            localized_command("clear", "Clears the queue"),
+            localized_command("cooldown", "Keep members from spamming commands")
+                .default_member_permissions(Permissions::ADMINISTRATOR)
                 .add_option(
-                    CreateCommandOption::new(
+                    localized_option(
                         CommandOptionType::SubCommand,
-                        "next",
-                        "Add a track to be played up next",
+                        "command",
+                        "Make each member wait between uses of a command",
                     )
                     .add_sub_option(
-                        CreateCommandOption::new(
+                        localized_option(
                             CommandOptionType::String,
-                            "query",
-                            "The media to play",
+                            "name",
+                            "The command, such as play",
+                        )
+                        .required(true),
+                    )
+                    .add_sub_option(
+                        localized_option(
+                            CommandOptionType::Integer,
+                            "seconds",
+                            "How long to wait, 0 removes the cooldown",
                         )
+                        .min_int_value(0)
+                        .max_int_value(MAX_COOLDOWN)
                         .required(true),
                     ),
                 )
                 .add_option(
-                    CreateCommandOption::new(
+                    localized_option(
                         CommandOptionType::SubCommand,
-                        "jump",
-                        "Instantly plays a track, skipping the current one",
+                        "queue",
+                        "Limit how often the queue can be changed by anyone",
                     )
                     .add_sub_option(
-                        CreateCommandOption::new(
-                            CommandOptionType::String,
-                            "query",
-                            "The media to play",
+                        localized_option(
+                            CommandOptionType::Integer,
+                            "commands",
+                            "How many queue changes are allowed, 0 lifts the limit",
                         )
+                        .min_int_value(0)
+                        .max_int_value(100)
                         .required(true),
+                    )
+                    .add_sub_option(
+                        localized_option(
+                            CommandOptionType::Integer,
+                            "seconds",
+                            "Within how many seconds, defaults to a minute",
+                        )
+                        .min_int_value(1)
+                        .max_int_value(MAX_COOLDOWN)
+                        .required(false),
                     ),
                 )
+                .add_option(localized_option(
+                    CommandOptionType::SubCommand,
+                    "show",
+                    "Show the current cooldowns",
+                )),
+            localized_command(
+                "djrole",
+                "Restrict clearing, stopping, shuffling and removing tracks to a role",
+            )
+            .default_member_permissions(Permissions::ADMINISTRATOR)
+            .add_option(
+                localized_option(
+                    CommandOptionType::Role,
+                    "role",
+                    "The DJ role, leave empty to let everyone manage the queue again",
+                )
+                .required(false),
+            ),
+            localized_command(
+                "emptypause",
+                "Toggles whether to pause while nobody is listening",
+            )
+            .default_member_permissions(Permissions::ADMINISTRATOR),
+            localized_command("filter", "Keep unwanted tracks out of the queue")
+                .default_member_permissions(Permissions::ADMINISTRATOR)
                 .add_option(
-                    CreateCommandOption::new(
+                    localized_option(
                         CommandOptionType::SubCommand,
-                        "all",
-                        "Add all tracks if the URL refers to a video and a playlist",
+                        "title",
+                        "Block tracks whose title matches a pattern",
                     )
                     .add_sub_option(
-                        CreateCommandOption::new(
+                        localized_option(
                             CommandOptionType::String,
-                            "query",
-                            "The media to play",
+                            "pattern",
+                            "A case-insensitive regular expression, leave empty to remove it",
                         )
-                        .required(true),
+                        .required(false),
                     ),
                 )
                 .add_option(
-                    CreateCommandOption::new(
+                    localized_option(
                         CommandOptionType::SubCommand,
-                        "reverse",
-                        "Add a playlist to the queue in reverse order",
+                        "channel",
+                        "Block tracks whose channel matches a pattern",
                     )
                     .add_sub_option(
-                        CreateCommandOption::new(
+                        localized_option(
                             CommandOptionType::String,
-                            "query",
-                            "The media to play",
+                            "pattern",
+                            "A case-insensitive regular expression, leave empty to remove it",
+                        )
+                        .required(false),
+                    ),
+                )
+                .add_option(
+                    localized_option(
+                        CommandOptionType::SubCommand,
+                        "livestreams",
+                        "Set whether to block livestreams",
+                    )
+                    .add_sub_option(
+                        localized_option(
+                            CommandOptionType::Boolean,
+                            "block",
+                            "Whether to block livestreams",
                         )
                         .required(true),
                     ),
                 )
                 .add_option(
-                    CreateCommandOption::new(
+                    localized_option(
                         CommandOptionType::SubCommand,
-                        "shuffle",
-                        "Add a playlist to the queue in random order",
+                        "agerestricted",
+                        "Set whether to block age-restricted content",
                     )
                     .add_sub_option(
-                        CreateCommandOption::new(
-                            CommandOptionType::String,
-                            "query",
-                            "The media to play",
+                        localized_option(
+                            CommandOptionType::Boolean,
+                            "block",
+                            "Whether to block age-restricted content",
+                        )
+                        .required(true),
+                    ),
+                )
+                .add_option(
+                    localized_option(
+                        CommandOptionType::SubCommand,
+                        "duration",
+                        "Set the longest a track may be",
+                    )
+                    .add_sub_option(
+                        localized_option(
+                            CommandOptionType::Integer,
+                            "minutes",
+                            "Maximum length in minutes, leave empty to allow any length",
+                        )
+                        .min_int_value(1)
+                        .required(false),
+                    ),
+                )
+                .add_option(localized_option(
+                    CommandOptionType::SubCommand,
+                    "show",
+                    "Show the current filters",
+                )),
+            localized_command("idle", "Configure what happens when the bot sits idle")
+                .default_member_permissions(Permissions::ADMINISTRATOR)
+                .add_option(
+                    localized_option(
+                        CommandOptionType::SubCommand,
+                        "timeout",
+                        "Set how long the bot may stay idle before leaving",
+                    )
+                    .add_sub_option(
+                        localized_option(
+                            CommandOptionType::Integer,
+                            "seconds",
+                            "Seconds to stay idle before leaving, 0 to never leave",
+                        )
+                        .required(true)
+                        .min_int_value(0),
+                    ),
+                )
+                .add_option(
+                    localized_option(
+                        CommandOptionType::SubCommand,
+                        "announce",
+                        "Set whether and where to announce leaving due to inactivity",
+                    )
+                    .add_sub_option(
+                        localized_option(
+                            CommandOptionType::Boolean,
+                            "enabled",
+                            "Whether to announce leaving",
                         )
                         .required(true),
+                    )
+                    .add_sub_option(
+                        localized_option(
+                            CommandOptionType::Channel,
+                            "channel",
+                            "Where to announce, defaults to where the bot was summoned from",
+                        )
+                        .channel_types(vec![ChannelType::Text, ChannelType::News])
+                        .required(false),
+                    ),
+                ),
+            localized_command("language", "Choose the language the bot speaks")
+                .default_member_permissions(Permissions::ADMINISTRATOR)
+                .add_option(
+                    LANGUAGES
+                        .iter()
+                        .fold(
+                            localized_option(
+                                CommandOptionType::String,
+                                "language",
+                                "The language to speak, leave empty to follow each member's own",
+                            ),
+                            |option, (locale, name)| option.add_string_choice(*name, *locale),
+                        )
+                        .required(false),
+                ),
+            localized_command("leave", "Leave the voice channel the bot is connected to"),
+            localized_command("managesources", "Manage streaming from different sources"),
+            localized_command("musicban", "Stop a member from using the bot")
+                .default_member_permissions(Permissions::MODERATE_MEMBERS)
+                .add_option(
+                    localized_option(CommandOptionType::User, "user", "The member to ban")
+                        .required(true),
+                )
+                .add_option(
+                    localized_option(
+                        CommandOptionType::Integer,
+                        "minutes",
+                        "How long the ban lasts, leave empty to ban until lifted",
+                    )
+                    .min_int_value(1)
+                    .required(false),
+                )
+                .add_option(
+                    localized_option(
+                        CommandOptionType::String,
+                        "scope",
+                        "What the member can no longer do, defaults to everything",
+                    )
+                    .add_string_choice("everything", "all")
+                    .add_string_choice("adding tracks", "queue")
+                    .required(false),
+                ),
+            localized_command("musicunban", "Let a banned member use the bot again")
+                .default_member_permissions(Permissions::MODERATE_MEMBERS)
+                .add_option(
+                    localized_option(CommandOptionType::User, "user", "The member to unban")
+                        .required(true),
+                ),
+            localized_command("np", "Displays information about the current track"),
+            localized_command("pause", "Pauses the current track"),
+            localized_command("permissions", "Choose who can use each command")
+                .default_member_permissions(Permissions::ADMINISTRATOR),
+            localized_command("play", "Add a track to the queue").add_option(
+                localized_option(CommandOptionType::String, "query", "The media to play")
+                    .required(true),
+            ),
+            localized_command("superplay", "Add a track to the queue in a special way")
+                .add_option(
+                    localized_option(
+                        CommandOptionType::SubCommand,
+                        "next",
+                        "Add a track to be played up next",
+                    )
+                    .add_sub_option(
+                        localized_option(CommandOptionType::String, "query", "The media to play")
+                            .required(true),
+                    ),
+                )
+                .add_option(
+                    localized_option(
+                        CommandOptionType::SubCommand,
+                        "jump",
+                        "Instantly plays a track, skipping the current one",
+                    )
+                    .add_sub_option(
+                        localized_option(CommandOptionType::String, "query", "The media to play")
+                            .required(true),
                     ),
+                )
+                .add_option(
+                    localized_option(
+                        CommandOptionType::SubCommand,
+                        "all",
+                        "Add all tracks if the URL refers to a video and a playlist",
+                    )
+                    .add_sub_option(
+                        localized_option(CommandOptionType::String, "query", "The media to play")
+                            .required(true),
+                    )
+                    .add_sub_option(playlist_position_option(
+                        "start",
+                        "Position of the first track to add",
+                    ))
+                    .add_sub_option(playlist_position_option(
+                        "end",
+                        "Position of the last track to add",
+                    ))
+                    .add_sub_option(playlist_position_option(
+                        "limit",
+                        "Maximum number of tracks to add",
+                    )),
+                )
+                .add_option(
+                    localized_option(
+                        CommandOptionType::SubCommand,
+                        "reverse",
+                        "Add a playlist to the queue in reverse order",
+                    )
+                    .add_sub_option(
+                        localized_option(CommandOptionType::String, "query", "The media to play")
+                            .required(true),
+                    )
+                    .add_sub_option(playlist_position_option(
+                        "start",
+                        "Position of the first track to add",
+                    ))
+                    .add_sub_option(playlist_position_option(
+                        "end",
+                        "Position of the last track to add",
+                    ))
+                    .add_sub_option(playlist_position_option(
+                        "limit",
+                        "Maximum number of tracks to add",
+                    )),
+                )
+                .add_option(
+                    localized_option(
+                        CommandOptionType::SubCommand,
+                        "shuffle",
+                        "Add a playlist to the queue in random order",
+                    )
+                    .add_sub_option(
+                        localized_option(CommandOptionType::String, "query", "The media to play")
+                            .required(true),
+                    )
+                    .add_sub_option(playlist_position_option(
+                        "start",
+                        "Position of the first track to add",
+                    ))
+                    .add_sub_option(playlist_position_option(
+                        "end",
+                        "Position of the last track to add",
+                    ))
+                    .add_sub_option(playlist_position_option(
+                        "limit",
+                        "Maximum number of tracks to add",
+                    )),
                 ),
-            CreateCommand::new("queue").description("Shows the queue"),
-            CreateCommand::new("remove")
-                .description("Removes a track from the queue")
+            localized_command("queue", "Shows the queue"),
+            localized_command("remove", "Removes a track from the queue")
                 .add_option(
-                    CreateCommandOption::new(
+                    localized_option(
                         CommandOptionType::Integer,
                         "index",
                         "Position of the track in the queue (1 is the next track to be played)",
@@ -191,7 +743,7 @@ impl SerenityHandler {
                     .min_int_value(1),
                 )
                 .add_option(
-                    CreateCommandOption::new(
+                    localized_option(
                         CommandOptionType::Integer,
                         "until",
                         "Upper range track position to remove a range of tracks",
@@ -199,34 +751,91 @@ impl SerenityHandler {
                     .required(false)
                     .min_int_value(1),
                 ),
-            CreateCommand::new("repeat").description("Toggles looping for the current track"),
-            CreateCommand::new("resume").description("Resumes the current track"),
-            CreateCommand::new("seek")
-                .description("Seeks current track to the given position")
-                .add_option(
-                    CreateCommandOption::new(
-                        CommandOptionType::String,
-                        "timestamp",
-                        "Timestamp in the format HH:MM:SS",
-                    )
-                    .required(true),
-                ),
-            CreateCommand::new("shuffle").description("Shuffles the queue"),
-            CreateCommand::new("skip")
-                .description("Skips the current track")
-                .add_option(
-                    CreateCommandOption::new(
-                        CommandOptionType::Integer,
-                        "to",
-                        "Track index to skip to",
-                    )
+            localized_command("repeat", "Toggles looping for the current track"),
+            localized_command("resume", "Resumes the current track"),
+            localized_command("seek", "Seeks current track to the given position").add_option(
+                localized_option(
+                    CommandOptionType::String,
+                    "timestamp",
+                    "Timestamp in the format HH:MM:SS",
+                )
+                .required(true),
+            ),
+            localized_command("shuffle", "Shuffles the queue"),
+            localized_command("skip", "Skips the current track").add_option(
+                localized_option(CommandOptionType::Integer, "to", "Track index to skip to")
                     .required(false)
                     .min_int_value(1),
+            ),
+            localized_command(
+                "stagetopic",
+                "Toggles whether to set the stage topic to the current track",
+            )
+            .default_member_permissions(Permissions::ADMINISTRATOR),
+            localized_command("stop", "Stops the bot and clears the queue"),
+            localized_command("summon", "Summons the bot in your voice channel"),
+            localized_command("version", "Displays the current version"),
+            localized_command("vote", "Start a vote to change the queue without a DJ")
+                .add_option(localized_option(
+                    CommandOptionType::SubCommand,
+                    "stop",
+                    "Vote to stop the music and clear the queue",
+                ))
+                .add_option(localized_option(
+                    CommandOptionType::SubCommand,
+                    "clear",
+                    "Vote to clear the queue",
+                ))
+                .add_option(localized_option(
+                    CommandOptionType::SubCommand,
+                    "shuffle",
+                    "Vote to shuffle the queue",
+                ))
+                .add_option(
+                    localized_option(
+                        CommandOptionType::SubCommand,
+                        "remove",
+                        "Vote to remove a track from the queue",
+                    )
+                    .add_sub_option(
+                        localized_option(
+                            CommandOptionType::Integer,
+                            "index",
+                            "Position of the track in the queue (1 is the next track to be played)",
+                        )
+                        .required(true)
+                        .min_int_value(1),
+                    ),
                 ),
-            CreateCommand::new("stop").description("Stops the bot and clears the queue"),
-            CreateCommand::new("summon").description("Summons the bot in your voice channel"),
-            CreateCommand::new("version").description("Displays the current version"),
-            CreateCommand::new("voteskip").description("Starts a vote to skip the current track"),
+            localized_command(
+                "votepercent",
+                "Set the share of listeners needed to vote a track away",
+            )
+            .default_member_permissions(Permissions::ADMINISTRATOR)
+            .add_option(
+                localized_option(
+                    CommandOptionType::Integer,
+                    "percent",
+                    "Percentage of listeners that must vote",
+                )
+                .required(true)
+                .min_int_value(1)
+                .max_int_value(100),
+            )
+            .add_option(
+                localized_option(
+                    CommandOptionType::String,
+                    "vote",
+                    "Which vote to configure, defaults to skipping",
+                )
+                .add_string_choice("skip", "skip")
+                .add_string_choice("stop", "stop")
+                .add_string_choice("clear", "clear")
+                .add_string_choice("shuffle", "shuffle")
+                .add_string_choice("remove", "remove")
+                .required(false),
+            ),
+            localized_command("voteskip", "Starts a vote to skip the current track"),
         ];
 
         match Command::set_global_commands(&ctx.http, commands).await {
@@ -265,21 +874,36 @@ impl SerenityHandler {
     ) -> Result<(), ParrotError> {
         let command_name = command.data.name.as_str();
 
-        let guild_id = command.guild_id.ok_or(ParrotError::Other(
-            "This command can only be used in a server",
-        ))?;
+        let guild_id = command
+            .guild_id
+            .ok_or(ParrotError::Other(FAIL_GUILD_ONLY))?;
 
         // Clone the guild to avoid holding CacheRef across await points
         let guild = ctx
             .cache
             .guild(guild_id)
-            .ok_or(ParrotError::Other("Guild not found in cache"))?
+            .ok_or(ParrotError::Other(FAIL_GUILD_NOT_CACHED))?
             .clone();
 
+        // admins must always be able to lift the restriction from wherever they are
+        if command_name != "channels" {
+            self.check_text_channel(ctx, &guild, command.channel_id)
+                .await?;
+        }
+
+        let member = command.member.as_deref();
+        self.check_ban(ctx, guild_id, command_name, command.user.id, member)
+            .await?;
+
+        // explicit per-command policies take precedence over everything else
+        let policy = self
+            .check_policy(ctx, guild_id, command_name, member)
+            .await?;
+
         // get songbird voice client
         let manager = songbird::get(ctx)
             .await
-            .ok_or(ParrotError::Other("Voice manager not configured"))?;
+            .ok_or(ParrotError::Other(FAIL_VOICE_MANAGER))?;
 
         // parrot might have been disconnected manually
         if let Some(call) = manager.get(guild.id) {
@@ -294,8 +918,8 @@ impl SerenityHandler {
         let bot_id = ctx.cache.current_user().id;
 
         match command_name {
-            "autopause" | "clear" | "leave" | "pause" | "remove" | "repeat" | "resume" | "seek"
-            | "shuffle" | "skip" | "stop" | "voteskip" => {
+            "autopause" | "cancel" | "clear" | "leave" | "pause" | "remove" | "repeat"
+            | "resume" | "seek" | "shuffle" | "skip" | "stop" | "vote" | "voteskip" => {
                 match check_voice_connections(&guild, &user_id, &bot_id) {
                     Connection::User(_) | Connection::Neither => Err(ParrotError::NotConnected),
                     Connection::Bot(bot_channel_id) => {
@@ -314,7 +938,8 @@ impl SerenityHandler {
                     Connection::Bot(_) if command_name != "summon" => {
                         Err(ParrotError::WrongVoiceChannel)
                     }
-                    Connection::Separate(bot_channel_id, _) => {
+                    // summon decides for itself whether the bot may be moved
+                    Connection::Separate(bot_channel_id, _) if command_name != "summon" => {
                         Err(ParrotError::AlreadyConnected(bot_channel_id.mention()))
                     }
                     Connection::Neither => Err(ParrotError::AuthorNotFound),
@@ -328,13 +953,44 @@ impl SerenityHandler {
             _ => Ok(()),
         }?;
 
-        match command_name {
+        if policy.is_none() && DJ_COMMANDS.contains(&command_name) {
+            self.check_dj(ctx, command, &guild).await?;
+        }
+
+        // commands refused by the checks above don't count towards cooldowns, the rest are
+        // counted before running so that several sent at once can't all slip through
+        self.check_rate_limit(ctx, guild_id, command_name, user_id)
+            .await?;
+
+        // remember the queue so the audit log can tell what the command took out of it
+        let queue_before = match AUDITED_QUEUE_COMMANDS.contains(&command_name) {
+            true => snapshot_queue(ctx, guild_id).await,
+            false => None,
+        };
+
+        let result = match command_name {
+            "alwayson" => always_on(ctx, command).await,
+            "auditlog" => auditlog(ctx, command).await,
+            "autoleave" => autoleave(ctx, command).await,
             "autopause" => autopause(ctx, command).await,
+            "broadcast" => broadcast(ctx, command).await,
+            "cache" => cache(ctx, command).await,
+            "cancel" => cancel(ctx, command).await,
+            "channels" => channels(ctx, command).await,
             "clear" => clear(ctx, command).await,
+            "cooldown" => cooldown(ctx, command).await,
+            "djrole" => djrole(ctx, command).await,
+            "emptypause" => emptypause(ctx, command).await,
+            "filter" => filter(ctx, command).await,
+            "idle" => idle(ctx, command).await,
+            "language" => language(ctx, command).await,
             "leave" => leave(ctx, command).await,
             "managesources" => allow(ctx, command).await,
+            "musicban" => musicban(ctx, command).await,
+            "musicunban" => musicunban(ctx, command).await,
             "np" => now_playing(ctx, command).await,
             "pause" => pause(ctx, command).await,
+            "permissions" => permissions(ctx, command).await,
             "play" | "superplay" => play(ctx, command).await,
             "queue" => queue(ctx, command).await,
             "remove" => remove(ctx, command).await,
@@ -343,12 +999,274 @@ impl SerenityHandler {
             "seek" => seek(ctx, command).await,
             "shuffle" => shuffle(ctx, command).await,
             "skip" => skip(ctx, command).await,
+            "stagetopic" => stagetopic(ctx, command).await,
             "stop" => stop(ctx, command).await,
             "summon" => summon(ctx, command, true).await,
             "version" => version(ctx, command).await,
+            "vote" => vote(ctx, command).await,
+            "votepercent" => votepercent(ctx, command).await,
             "voteskip" => voteskip(ctx, command).await,
             _ => unreachable!(),
+        };
+
+        if let (Ok(()), Some(before)) = (&result, queue_before) {
+            log_queue_change(ctx, guild_id, user_id, &command.data.name, before).await;
+        }
+
+        result
+    }
+
+    /// Fails with the channels that do accept commands if this one doesn't.
+    async fn check_text_channel(
+        &self,
+        ctx: &Context,
+        guild: &Guild,
+        channel_id: ChannelId,
+    ) -> Result<(), ParrotError> {
+        let data = ctx.data.read().await;
+        let settings = data.get::<GuildSettingsMap>().unwrap();
+        let Some(guild_settings) = settings.get(&guild.id) else {
+            return Ok(());
+        };
+
+        // threads count as part of the channel they were started in
+        let parent_id = guild
+            .threads
+            .iter()
+            .find(|thread| thread.id == channel_id)
+            .and_then(|thread| thread.parent_id);
+
+        let allowed = guild_settings.is_text_channel_allowed(channel_id)
+            || parent_id.is_some_and(|parent_id| guild_settings.is_text_channel_allowed(parent_id));
+
+        let mentions = mention_all(&guild_settings.allowed_text_channels);
+        verify(allowed, ParrotError::TextChannelNotAllowed(mentions))?;
+        Ok(())
+    }
+
+    /// Returns the policy configured for a command, failing if the member isn't allowed by it.
+    async fn check_policy(
+        &self,
+        ctx: &Context,
+        guild_id: GuildId,
+        command_name: &str,
+        member: Option<&Member>,
+    ) -> Result<Option<CommandPolicy>, ParrotError> {
+        let (policy, dj_role) = {
+            let data = ctx.data.read().await;
+            let settings = data.get::<GuildSettingsMap>().unwrap();
+            settings
+                .get(&guild_id)
+                .map_or((None, None), |guild_settings| {
+                    (
+                        guild_settings.command_policies.get(command_name).copied(),
+                        guild_settings.dj_role,
+                    )
+                })
+        };
+
+        if let Some(policy) = policy {
+            let is_dj = dj_role.is_some_and(|dj_role| has_role(member, dj_role));
+            verify(
+                policy.allows(is_dj, is_admin(member)),
+                ParrotError::CommandNotAllowed(policy),
+            )?;
+        }
+
+        Ok(policy)
+    }
+
+    /// Speaks the language picked for the guild, or otherwise the one the user has Discord in.
+    async fn get_locale(
+        &self,
+        ctx: &Context,
+        guild_id: Option<GuildId>,
+        user_locale: &str,
+    ) -> String {
+        let guild_locale = match guild_id {
+            Some(guild_id) => get_guild_locale(&ctx.data, guild_id).await,
+            None => None,
+        };
+        guild_locale.unwrap_or_else(|| user_locale.to_string())
+    }
+
+    /// Fails if the user is banned from using the given command in this guild, unless they are an admin.
+    async fn check_ban(
+        &self,
+        ctx: &Context,
+        guild_id: GuildId,
+        command_name: &str,
+        user_id: UserId,
+        member: Option<&Member>,
+    ) -> Result<(), ParrotError> {
+        // admins can't be locked out of the bot, as they could lift the ban anyway
+        if is_admin(member) {
+            return Ok(());
+        }
+
+        let data = ctx.data.read().await;
+        let settings = data.get::<GuildSettingsMap>().unwrap();
+        let ban = settings.get(&guild_id).and_then(|guild_settings| {
+            guild_settings.get_ban(user_id, Timestamp::now().unix_timestamp())
+        });
+
+        match ban {
+            Some(ban) if ban.applies_to(command_name) => Err(ParrotError::MusicBanned(ban)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Fails with the time left if the user is on cooldown for the command or the guild
+    /// changed its queue too often lately, otherwise counts the command towards both.
+    async fn check_rate_limit(
+        &self,
+        ctx: &Context,
+        guild_id: GuildId,
+        command_name: &str,
+        user_id: UserId,
+    ) -> Result<(), ParrotError> {
+        let (cooldown, queue_limit) = {
+            let data = ctx.data.read().await;
+            let settings = data.get::<GuildSettingsMap>().unwrap();
+            settings
+                .get(&guild_id)
+                .map_or((None, None), |guild_settings| {
+                    (
+                        guild_settings.command_cooldowns.get(command_name).copied(),
+                        guild_settings.queue_rate_limit,
+                    )
+                })
+        };
+
+        // nothing to enforce, so there's no need to keep track of the command either
+        let queue_limit = queue_limit.filter(|_| QUEUE_MODIFYING_COMMANDS.contains(&command_name));
+        if cooldown.is_none() && queue_limit.is_none() {
+            return Ok(());
+        }
+
+        let mut data = ctx.data.write().await;
+        let cache_map = data.get_mut::<GuildCacheMap>().unwrap();
+        let rate_limiter = &mut cache_map.entry(guild_id).or_default().rate_limiter;
+        let now = Instant::now();
+
+        if let Some(left) = cooldown.and_then(|cooldown| {
+            rate_limiter.cooldown_left(user_id, command_name, Duration::from_secs(cooldown), now)
+        }) {
+            return Err(ParrotError::RateLimited(left));
+        }
+
+        if let Some(left) = queue_limit.and_then(|limit| rate_limiter.rate_limit_left(limit, now)) {
+            return Err(ParrotError::RateLimited(left));
+        }
+
+        rate_limiter.record(user_id, command_name, now);
+        Ok(())
+    }
+
+    /// Runs the same checks on a button press as on the command it stands in for.
+    pub async fn check_button(
+        &self,
+        ctx: &Context,
+        guild_id: GuildId,
+        command_name: &str,
+        component: &ComponentInteraction,
+    ) -> Result<(), ParrotError> {
+        let guild = ctx
+            .cache
+            .guild(guild_id)
+            .ok_or(ParrotError::Other(FAIL_GUILD_NOT_CACHED))?
+            .clone();
+
+        self.check_text_channel(ctx, &guild, component.channel_id)
+            .await?;
+        let member = component.member.as_ref();
+        self.check_ban(ctx, guild_id, command_name, component.user.id, member)
+            .await?;
+        self.check_policy(ctx, guild_id, command_name, member)
+            .await?;
+        self.check_rate_limit(ctx, guild_id, command_name, component.user.id)
+            .await?;
+        Ok(())
+    }
+
+    /// Votes to skip from the button on now playing messages, replying only to the voter on failure.
+    async fn vote_from_button(&self, ctx: &Context, component: &ComponentInteraction) {
+        let result = match component.guild_id {
+            Some(guild_id) => match self
+                .check_button(ctx, guild_id, "voteskip", component)
+                .await
+            {
+                Ok(()) => cast_skip_vote(ctx, guild_id, component.user.id)
+                    .await
+                    .map(|message| message.to_string()),
+                Err(err) => Err(err),
+            },
+            None => Err(ParrotError::Other(FAIL_GUILD_ONLY)),
+        };
+
+        let (content, ephemeral) = match result {
+            Ok(content) => (content, false),
+            Err(err) => (err.to_string(), true),
+        };
+
+        let response = CreateInteractionResponse::Message(
+            CreateInteractionResponseMessage::new()
+                .add_embed(CreateEmbed::new().description(content))
+                .ephemeral(ephemeral),
+        );
+        if let Err(e) = component.create_response(&ctx.http, response).await {
+            eprintln!("[WARN] Failed to respond to skip vote: {}", e);
+        }
+    }
+
+    /// Lets DJs, admins and the only listener in the channel use destructive commands,
+    /// along with anyone who only affects tracks they queued themselves.
+    async fn check_dj(
+        &self,
+        ctx: &Context,
+        command: &serenity::all::CommandInteraction,
+        guild: &Guild,
+    ) -> Result<(), ParrotError> {
+        let dj_role = {
+            let data = ctx.data.read().await;
+            let settings = data.get::<GuildSettingsMap>().unwrap();
+            settings
+                .get(&guild.id)
+                .and_then(|guild_settings| guild_settings.dj_role)
+        };
+
+        let Some(dj_role) = dj_role else {
+            return Ok(());
+        };
+
+        let member = command.member.as_deref();
+        if has_role(member, dj_role) || is_admin(member) {
+            return Ok(());
+        }
+
+        let bot_id = ctx.cache.current_user().id;
+        let listeners = get_voice_channel_for_user(guild, &bot_id)
+            .map(|channel_id| get_listeners(guild, channel_id))
+            .unwrap_or_default();
+
+        if listeners == [command.user.id] {
+            return Ok(());
         }
+
+        let queue = match songbird::get(ctx)
+            .await
+            .and_then(|manager| manager.get(guild.id))
+        {
+            Some(call) => call.lock().await.queue().current_queue(),
+            None => vec![],
+        };
+
+        let requested_all = affected_tracks(command, &queue)
+            .iter()
+            .all(|track| get_track_requester(track) == Some(command.user.id));
+
+        verify(requested_all, ParrotError::NotDj)?;
+        Ok(())
     }
 
     async fn self_deafen(&self, ctx: &Context, guild: Option<GuildId>, new: VoiceState) {
@@ -374,6 +1292,24 @@ impl SerenityHandler {
         interaction: &mut serenity::all::CommandInteraction,
         err: ParrotError,
     ) {
+        // only the person in the wrong channel or under a ban needs to see why they were refused
+        if matches!(
+            err,
+            ParrotError::TextChannelNotAllowed(_)
+                | ParrotError::MusicBanned(_)
+                | ParrotError::RateLimited(_)
+        ) {
+            let response = CreateInteractionResponse::Message(
+                CreateInteractionResponseMessage::new()
+                    .add_embed(CreateEmbed::new().description(format!("{err}")))
+                    .ephemeral(true),
+            );
+            if let Err(e) = interaction.create_response(&ctx.http, response).await {
+                eprintln!("[WARN] Failed to respond with private error: {}", e);
+            }
+            return;
+        }
+
         create_response_text(&ctx.http, interaction, &format!("{err}"))
             .await
             .expect("failed to create response");
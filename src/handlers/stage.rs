@@ -0,0 +1,97 @@
+use serenity::{
+    all::{ChannelType, CreateStageInstance, EditStageInstance, EditVoiceState},
+    client::Context,
+    model::{
+        guild::Guild,
+        id::{ChannelId, GuildId},
+    },
+};
+
+use crate::{
+    errors::ParrotError,
+    messaging::messages::{FAIL_CHANNEL_NOT_CACHED, FAIL_STAGE_SPEAKER},
+};
+
+const STAGE_TOPIC_MAX_LEN: usize = 120;
+
+pub enum StageRole {
+    Speaker,
+    RequestedToSpeak,
+}
+
+pub fn is_stage_channel(guild: &Guild, channel_id: ChannelId) -> bool {
+    guild
+        .channels
+        .get(&channel_id)
+        .is_some_and(|channel| channel.kind == ChannelType::Stage)
+}
+
+/// Bots join stages as suppressed audience members, so step onto the stage right away when
+/// allowed to, or else raise a hand for a stage moderator to invite the bot up.
+pub async fn become_speaker(
+    ctx: &Context,
+    guild: &Guild,
+    channel_id: ChannelId,
+) -> Result<StageRole, ParrotError> {
+    let channel = guild
+        .channels
+        .get(&channel_id)
+        .ok_or(ParrotError::Other(FAIL_CHANNEL_NOT_CACHED))?;
+
+    let bot_id = ctx.cache.current_user().id;
+    let member = guild.member(ctx, bot_id).await?;
+    let permissions = guild.user_permissions_in(channel, &member);
+
+    if permissions.mute_members() {
+        channel
+            .edit_own_voice_state(&ctx.http, EditVoiceState::new().suppress(false))
+            .await?;
+        return Ok(StageRole::Speaker);
+    }
+
+    if permissions.request_to_speak() {
+        channel
+            .edit_own_voice_state(&ctx.http, EditVoiceState::new().request_to_speak(true))
+            .await?;
+        return Ok(StageRole::RequestedToSpeak);
+    }
+
+    Err(ParrotError::Other(FAIL_STAGE_SPEAKER))
+}
+
+/// Sets the topic of the stage the bot is on, starting the stage if it isn't live yet.
+pub async fn update_stage_topic(
+    ctx: &Context,
+    guild_id: GuildId,
+    channel_id: ChannelId,
+    topic: &str,
+) {
+    let is_stage = ctx
+        .cache
+        .guild(guild_id)
+        .is_some_and(|guild| is_stage_channel(&guild, channel_id));
+
+    if !is_stage {
+        return;
+    }
+
+    let topic: String = topic.chars().take(STAGE_TOPIC_MAX_LEN).collect();
+    if topic.trim().is_empty() {
+        return;
+    }
+
+    let edited = channel_id
+        .edit_stage_instance(&ctx.http, EditStageInstance::new().topic(&topic))
+        .await;
+
+    if edited.is_ok() {
+        return;
+    }
+
+    if let Err(e) = channel_id
+        .create_stage_instance(&ctx.http, CreateStageInstance::new(topic))
+        .await
+    {
+        eprintln!("[WARN] Failed to update stage topic: {}", e);
+    }
+}
@@ -14,6 +14,7 @@ use crate::{
         voteskip::forget_skip_votes,
     },
     guild::{cache::GuildCacheMap, settings::GuildSettingsMap},
+    messaging::i18n::with_guild_locale,
 };
 
 pub struct TrackEndHandler {
@@ -60,7 +61,9 @@ impl EventHandler for ModifyQueueHandler {
         let queue = handler.queue().current_queue();
         drop(handler);
 
-        update_queue_messages(&self.http, &self.ctx_data, &queue, self.guild_id).await;
+        // queue messages are shared by everyone, so they're kept in the guild's language
+        let update = update_queue_messages(&self.http, &self.ctx_data, &queue, self.guild_id);
+        with_guild_locale(&self.ctx_data, self.guild_id, update).await;
         None
     }
 }
@@ -0,0 +1,202 @@
+use serenity::{
+    client::Context,
+    model::id::GuildId,
+    prelude::{Mutex, RwLock, TypeMap},
+};
+use songbird::{tracks::PlayMode, Call};
+use std::{sync::Arc, time::Duration};
+use tokio_util::sync::CancellationToken;
+
+use crate::{
+    commands::cancel::cancel_imports,
+    connection::{get_listeners, get_voice_channel_for_user},
+    guild::{
+        cache::GuildCacheMap,
+        settings::{GuildSettings, GuildSettingsMap},
+    },
+    messaging::{
+        i18n::{tr, with_guild_locale},
+        messages::AUTOLEAVE_ALERT,
+    },
+};
+
+/// Keeps track of whether anyone is still listening in the bot's voice channel.
+/// Once the channel empties out, the bot either pauses until someone comes back or starts
+/// a grace period after which it leaves, unless someone joins back in the meantime.
+pub async fn handle_listener_change(ctx: &Context, guild_id: GuildId) {
+    let Some(listeners) = count_listeners(ctx, guild_id) else {
+        // this runs for every voice update in every guild, so only contend for the
+        // write lock if the bot left something behind when it was last connected
+        if has_listener_state(&ctx.data, guild_id).await {
+            stop_autoleave_timer(ctx, guild_id).await;
+            forget_auto_pause(&ctx.data, guild_id).await;
+        }
+        return;
+    };
+
+    let (autoleave, grace, pause_when_empty, always_on) = {
+        let data = ctx.data.read().await;
+        let settings = data.get::<GuildSettingsMap>().unwrap();
+        let default_settings = GuildSettings::new(guild_id);
+        let guild_settings = settings.get(&guild_id).unwrap_or(&default_settings);
+
+        (
+            guild_settings.autoleave,
+            guild_settings.autoleave_grace,
+            guild_settings.pause_when_empty,
+            guild_settings.always_on_channel.is_some(),
+        )
+    };
+
+    match listeners {
+        // pausing is an alternative to leaving, so it takes precedence
+        0 if pause_when_empty => {
+            stop_autoleave_timer(ctx, guild_id).await;
+            pause_while_empty(ctx, guild_id).await;
+        }
+        // in 24/7 mode the bot stays put no matter what
+        0 if autoleave && !always_on => start_autoleave_timer(ctx, guild_id, grace).await,
+        0 => stop_autoleave_timer(ctx, guild_id).await,
+        _ => {
+            stop_autoleave_timer(ctx, guild_id).await;
+            resume_if_auto_paused(ctx, guild_id).await;
+        }
+    }
+}
+
+/// Counts who's listening in the bot's voice channel, if it's in one.
+fn count_listeners(ctx: &Context, guild_id: GuildId) -> Option<usize> {
+    let bot_id = ctx.cache.current_user().id;
+    let guild = ctx.cache.guild(guild_id)?;
+    let channel_id = get_voice_channel_for_user(&guild, &bot_id)?;
+    Some(get_listeners(&guild, channel_id).len())
+}
+
+async fn has_listener_state(data: &Arc<RwLock<TypeMap>>, guild_id: GuildId) -> bool {
+    let data = data.read().await;
+    let cache_map = data.get::<GuildCacheMap>().unwrap();
+
+    cache_map
+        .get(&guild_id)
+        .is_some_and(|cache| cache.autoleave_timer.is_some() || cache.auto_paused)
+}
+
+async fn start_autoleave_timer(ctx: &Context, guild_id: GuildId, grace: u64) {
+    let mut data = ctx.data.write().await;
+    let cache_map = data.get_mut::<GuildCacheMap>().unwrap();
+    let cache = cache_map.entry(guild_id).or_default();
+
+    if cache.autoleave_timer.is_some() {
+        return;
+    }
+
+    let token = CancellationToken::new();
+    cache.autoleave_timer = Some(token.clone());
+    drop(data);
+
+    let ctx = ctx.clone();
+    tokio::spawn(async move {
+        tokio::select! {
+            _ = token.cancelled() => {}
+            _ = tokio::time::sleep(Duration::from_secs(grace)) => {
+                // someone may have come back without the timer being stopped in time
+                if count_listeners(&ctx, guild_id) == Some(0) {
+                    leave_empty_channel(&ctx, guild_id).await;
+                } else {
+                    stop_autoleave_timer(&ctx, guild_id).await;
+                }
+            }
+        }
+    });
+}
+
+async fn stop_autoleave_timer(ctx: &Context, guild_id: GuildId) {
+    let mut data = ctx.data.write().await;
+    let cache_map = data.get_mut::<GuildCacheMap>().unwrap();
+
+    if let Some(token) = cache_map
+        .get_mut(&guild_id)
+        .and_then(|cache| cache.autoleave_timer.take())
+    {
+        token.cancel();
+    }
+}
+
+/// Pauses the current track while nobody is around to hear it.
+/// Tracks that were already paused are left alone, so a manual pause is never undone later.
+async fn pause_while_empty(ctx: &Context, guild_id: GuildId) {
+    let Some(call) = get_call(ctx, guild_id).await else {
+        return;
+    };
+
+    let handler = call.lock().await;
+    let Some(track) = handler.queue().current() else {
+        return;
+    };
+
+    let is_playing = track
+        .get_info()
+        .await
+        .map(|info| matches!(info.playing, PlayMode::Play))
+        .unwrap_or_default();
+
+    if !is_playing || handler.queue().pause().is_err() {
+        return;
+    }
+    drop(handler);
+
+    let mut data = ctx.data.write().await;
+    let cache_map = data.get_mut::<GuildCacheMap>().unwrap();
+    cache_map.entry(guild_id).or_default().auto_paused = true;
+}
+
+async fn resume_if_auto_paused(ctx: &Context, guild_id: GuildId) {
+    if !forget_auto_pause(&ctx.data, guild_id).await {
+        return;
+    }
+
+    if let Some(call) = get_call(ctx, guild_id).await {
+        call.lock().await.queue().resume().ok();
+    }
+}
+
+/// Clears the flag marking the current track as paused by the bot rather than by a user,
+/// returning whether it was set.
+pub async fn forget_auto_pause(data: &Arc<RwLock<TypeMap>>, guild_id: GuildId) -> bool {
+    let mut data = data.write().await;
+    let cache_map = data.get_mut::<GuildCacheMap>().unwrap();
+
+    cache_map
+        .get_mut(&guild_id)
+        .map(|cache| std::mem::take(&mut cache.auto_paused))
+        .unwrap_or_default()
+}
+
+async fn get_call(ctx: &Context, guild_id: GuildId) -> Option<Arc<Mutex<Call>>> {
+    songbird::get(ctx).await?.get(guild_id)
+}
+
+async fn leave_empty_channel(ctx: &Context, guild_id: GuildId) {
+    let mut data = ctx.data.write().await;
+    let cache_map = data.get_mut::<GuildCacheMap>().unwrap();
+    let cache = cache_map.entry(guild_id).or_default();
+    cache.autoleave_timer = None;
+    let text_channel = cache.text_channel;
+    drop(data);
+
+    let Some(manager) = songbird::get(ctx).await else {
+        return;
+    };
+
+    cancel_imports(&ctx.data, guild_id).await;
+    if manager.remove(guild_id).await.is_err() {
+        return;
+    }
+
+    if let Some(channel_id) = text_channel {
+        let alert = with_guild_locale(&ctx.data, guild_id, async { tr(AUTOLEAVE_ALERT) });
+        if let Err(e) = channel_id.say(&ctx.http, alert.await).await {
+            eprintln!("[WARN] Failed to send auto-leave alert: {}", e);
+        }
+    }
+}
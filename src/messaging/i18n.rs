@@ -0,0 +1,122 @@
+use lazy_static::lazy_static;
+use serenity::{
+    all::{CommandOptionType, CreateCommand, CreateCommandOption},
+    model::id::GuildId,
+    prelude::{RwLock, TypeMap},
+};
+use std::{collections::HashMap, future::Future, sync::Arc};
+
+use crate::guild::settings::GuildSettingsMap;
+
+/// Languages the bot can speak as Discord locale codes and their own names.
+/// English is what the messages are written in, so it needs no catalog.
+pub const LANGUAGES: [(&str, &str); 3] = [
+    ("en-US", "English"),
+    ("es-ES", "Español"),
+    ("pt-BR", "Português (Brasil)"),
+];
+
+/// Translation catalogs keyed by locale, each mapping the English text to its translation.
+const CATALOG_SOURCES: [(&str, &str); 2] = [
+    ("es-ES", include_str!("../../locales/es-ES.json")),
+    ("pt-BR", include_str!("../../locales/pt-BR.json")),
+];
+
+lazy_static! {
+    static ref CATALOGS: HashMap<&'static str, HashMap<String, String>> = CATALOG_SOURCES
+        .iter()
+        .map(|(locale, source)| {
+            let catalog = serde_json::from_str(source).unwrap_or_else(|err| {
+                eprintln!("[ERROR] Failed to parse the {} catalog: {}", locale, err);
+                HashMap::new()
+            });
+            (*locale, catalog)
+        })
+        .collect();
+}
+
+tokio::task_local! {
+    static LOCALE: Option<&'static str>;
+}
+
+/// Picks the catalog for a Discord locale, matching on the language alone if the
+/// region differs, so `es-419` is served from `es-ES`.
+pub fn resolve_locale(locale: &str) -> Option<&'static str> {
+    let language = locale.split('-').next().unwrap_or(locale);
+
+    CATALOG_SOURCES
+        .iter()
+        .map(|(catalog, _)| *catalog)
+        .find(|catalog| catalog.eq_ignore_ascii_case(locale))
+        .or_else(|| {
+            CATALOG_SOURCES
+                .iter()
+                .map(|(catalog, _)| *catalog)
+                .find(|catalog| catalog.split('-').next() == Some(language))
+        })
+}
+
+/// Translates an English message into the given locale, leaving it as is if there's no translation.
+pub fn tr_in(locale: Option<&str>, text: &'static str) -> &'static str {
+    locale
+        .and_then(|locale| CATALOGS.get(locale))
+        .and_then(|catalog| catalog.get(text))
+        .map_or(text, |translation| translation.as_str())
+}
+
+/// Translates an English message into the language of whatever is currently being handled.
+pub fn tr(text: &'static str) -> &'static str {
+    tr_in(LOCALE.try_with(|locale| *locale).ok().flatten(), text)
+}
+
+/// Runs a future with its messages translated into the given locale, if there's a catalog for it.
+pub async fn with_locale<F: Future>(locale: Option<&str>, future: F) -> F::Output {
+    LOCALE.scope(locale.and_then(resolve_locale), future).await
+}
+
+/// Returns the language configured for a guild, if it picked one over each member's own.
+pub async fn get_guild_locale(data: &Arc<RwLock<TypeMap>>, guild_id: GuildId) -> Option<String> {
+    let data = data.read().await;
+    let settings = data.get::<GuildSettingsMap>()?;
+    settings.get(&guild_id)?.locale.clone()
+}
+
+/// Runs a future outside of any interaction in the language configured for the guild.
+pub async fn with_guild_locale<F: Future>(
+    data: &Arc<RwLock<TypeMap>>,
+    guild_id: GuildId,
+    future: F,
+) -> F::Output {
+    let locale = get_guild_locale(data, guild_id).await;
+    with_locale(locale.as_deref(), future).await
+}
+
+/// Creates a slash command with its description translated into every available language.
+pub fn localized_command(name: &str, description: &'static str) -> CreateCommand {
+    CATALOG_SOURCES.iter().fold(
+        CreateCommand::new(name).description(description),
+        |command, (locale, _)| match tr_in(Some(locale), description) {
+            translation if translation != description => {
+                command.description_localized(*locale, translation)
+            }
+            _ => command,
+        },
+    )
+}
+
+/// Creates a command option with its description translated into every available language.
+pub fn localized_option(
+    kind: CommandOptionType,
+    name: &str,
+    description: &'static str,
+) -> CreateCommandOption {
+    CATALOG_SOURCES.iter().fold(
+        CreateCommandOption::new(kind, name, description),
+        |option, (locale, _)| match tr_in(Some(locale), description) {
+            translation if translation != description => {
+                option.description_localized(*locale, translation)
+            }
+            _ => option,
+        },
+    )
+}
@@ -1,76 +1,477 @@
-use std::fmt::Display;
+use std::{fmt::Display, time::Duration};
 
 use serenity::model::mention::Mention;
 
-use crate::messaging::messages::*;
+use crate::{
+    commands::play::PlaylistReport,
+    guild::{
+        filter::ContentFilter,
+        rate_limit::QueueRateLimit,
+        settings::{BanScope, MusicBan},
+    },
+    messaging::i18n::tr,
+    messaging::messages::*,
+    sources::cache::CacheStats,
+    utils::{create_progress_bar, get_human_readable_timestamp},
+};
 
 const RELEASES_LINK: &str = "https://github.com/aquelemiguel/parrot/releases";
+const PLAYLIST_FAILURES_SHOWN: usize = 10;
+const PLAYLIST_ENTRY_MAX_LEN: usize = 80;
 
 #[derive(Debug)]
 pub enum ParrotMessage {
+    AlwaysOnDisabled,
+    AlwaysOnEnabled {
+        channel: Mention,
+        playlist: Option<String>,
+    },
+    AuditLogCleared,
+    AuditLogSet {
+        channel: Mention,
+    },
+    AutoleaveOff,
+    AutoleaveOn {
+        grace: u64,
+    },
     AutopauseOff,
     AutopauseOn,
+    Broadcast {
+        status: bool,
+        nickname: bool,
+        presence: bool,
+    },
+    CacheStats {
+        stats: CacheStats,
+    },
+    Channels {
+        text: Vec<Mention>,
+        voice: Vec<Mention>,
+    },
     Clear,
+    Cooldowns {
+        cooldowns: Vec<(String, u64)>,
+        queue_limit: Option<QueueRateLimit>,
+    },
+    DjRoleCleared,
+    DjRoleSet {
+        role: Mention,
+    },
+    EmptyPauseOff,
+    EmptyPauseOn,
     Error,
+    Filters {
+        filter: ContentFilter,
+    },
+    IdleAnnounceOff,
+    IdleAnnounceOn {
+        channel: Option<Mention>,
+    },
+    IdleTimeout {
+        timeout: u64,
+    },
+    IdleTimeoutNever,
+    ImportCancelled,
+    Language {
+        name: Option<&'static str>,
+    },
     Leaving,
     LoopDisable,
     LoopEnable,
+    Moved {
+        mention: Mention,
+    },
+    MusicBanned {
+        user: Mention,
+        ban: MusicBan,
+    },
+    MusicNotBanned {
+        user: Mention,
+    },
+    MusicUnbanned {
+        user: Mention,
+    },
     NowPlaying,
     Pause,
     PlayAllFailed,
-    PlayDomainBanned { domain: String },
-    PlaylistQueued,
+    PlayDomainBanned {
+        domain: String,
+    },
+    PlaylistConfirm {
+        total: usize,
+    },
+    PlaylistProgress {
+        resolved: usize,
+        total: usize,
+    },
+    PlaylistQueued {
+        report: PlaylistReport,
+    },
     RemoveMultiple,
     Resume,
     Search,
-    Seek { timestamp: String },
+    Seek {
+        timestamp: String,
+    },
     Shuffle,
     Skip,
     SkipAll,
-    SkipTo { title: String, url: String },
+    SkipTo {
+        title: String,
+        url: String,
+    },
+    StageRequestedToSpeak {
+        mention: Mention,
+    },
+    StageTopicOff,
+    StageTopicOn,
     Stop,
-    Summon { mention: Mention },
-    Version { current: String },
-    VoteSkip { mention: Mention, missing: usize },
+    Summon {
+        mention: Mention,
+    },
+    Version {
+        current: String,
+    },
+    VoteExpired {
+        action: String,
+    },
+    VotePassed {
+        outcome: Box<ParrotMessage>,
+    },
+    VoteRemoved {
+        title: String,
+    },
+    VotePercent {
+        vote: String,
+        percent: u8,
+    },
+    VoteSkip {
+        mention: Mention,
+        missing: usize,
+    },
+    VoteTally {
+        action: String,
+        votes: usize,
+        required: usize,
+        ends: i64,
+    },
 }
 
 impl Display for ParrotMessage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::AutopauseOff => f.write_str(AUTOPAUSE_OFF),
-            Self::AutopauseOn => f.write_str(AUTOPAUSE_ON),
-            Self::Clear => f.write_str(CLEARED),
-            Self::Error => f.write_str(ERROR),
-            Self::Leaving => f.write_str(LEAVING),
-            Self::LoopDisable => f.write_str(LOOP_DISABLED),
-            Self::LoopEnable => f.write_str(LOOP_ENABLED),
-            Self::NowPlaying => f.write_str(QUEUE_NOW_PLAYING),
-            Self::Pause => f.write_str(PAUSED),
-            Self::PlaylistQueued => f.write_str(PLAY_PLAYLIST),
-            Self::PlayAllFailed => f.write_str(PLAY_ALL_FAILED),
-            Self::PlayDomainBanned { domain } => {
-                f.write_str(&format!("⚠️ **{}** {}", domain, PLAY_FAILED_BLOCKED_DOMAIN))
+            Self::AlwaysOnDisabled => f.write_str(tr(ALWAYS_ON_DISABLED)),
+            Self::AlwaysOnEnabled { channel, playlist } => {
+                let mut text = format!("{} {}!", tr(ALWAYS_ON_ENABLED), channel);
+                if let Some(playlist) = playlist {
+                    text.push_str(&format!("\n{} <{}>", tr(ALWAYS_ON_PLAYLIST), playlist));
+                }
+                f.write_str(&text)
+            }
+            Self::AuditLogCleared => f.write_str(tr(AUDIT_LOG_CLEARED)),
+            Self::AuditLogSet { channel } => {
+                f.write_str(&format!("{} {}!", tr(AUDIT_LOG_SET), channel))
+            }
+            Self::AutoleaveOff => f.write_str(tr(AUTOLEAVE_OFF)),
+            Self::AutoleaveOn { grace } => f.write_str(&format!(
+                "{} {} **{}s** {}",
+                tr(AUTOLEAVE_ON),
+                tr(AUTOLEAVE_GRACE_PREFIX),
+                grace,
+                tr(AUTOLEAVE_GRACE_SUFFIX)
+            )),
+            Self::AutopauseOff => f.write_str(tr(AUTOPAUSE_OFF)),
+            Self::AutopauseOn => f.write_str(tr(AUTOPAUSE_ON)),
+            Self::Broadcast {
+                status,
+                nickname,
+                presence,
+            } => {
+                let state = |enabled: &bool| match enabled {
+                    true => tr(BROADCAST_ON),
+                    false => tr(BROADCAST_OFF),
+                };
+                f.write_str(&format!(
+                    "{}\n{} **{}**\n{} **{}**\n{} **{}**",
+                    tr(BROADCAST_TITLE),
+                    tr(BROADCAST_STATUS),
+                    state(status),
+                    tr(BROADCAST_NICKNAME),
+                    state(nickname),
+                    tr(BROADCAST_PRESENCE),
+                    state(presence)
+                ))
+            }
+            Self::CacheStats { stats } => {
+                let lookups = stats.hits + stats.misses;
+                let hit_rate = match lookups {
+                    0 => 0.0,
+                    _ => stats.hits as f64 / lookups as f64 * 100.0,
+                };
+                f.write_str(&format!(
+                    "{}\n{} **{}/{}**\n{} **{}** ({:.1}%)\n{} **{}**",
+                    tr(CACHE_STATS_TITLE),
+                    tr(CACHE_STATS_ENTRIES),
+                    stats.entries,
+                    stats.capacity,
+                    tr(CACHE_STATS_HITS),
+                    stats.hits,
+                    hit_rate,
+                    tr(CACHE_STATS_MISSES),
+                    stats.misses
+                ))
+            }
+            Self::Channels { text, voice } => {
+                let list = |channels: &Vec<Mention>| match channels.is_empty() {
+                    true => tr(CHANNELS_ANY).to_string(),
+                    false => channels
+                        .iter()
+                        .map(|channel| channel.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                };
+                f.write_str(&format!(
+                    "{}\n{} {}\n{} {}",
+                    tr(CHANNELS_TITLE),
+                    tr(CHANNELS_TEXT),
+                    list(text),
+                    tr(CHANNELS_VOICE),
+                    list(voice)
+                ))
+            }
+            Self::Clear => f.write_str(tr(CLEARED)),
+            Self::Cooldowns {
+                cooldowns,
+                queue_limit,
+            } => {
+                let cooldowns = match cooldowns.is_empty() {
+                    true => tr(COOLDOWNS_NONE).to_string(),
+                    false => cooldowns
+                        .iter()
+                        .map(|(command, seconds)| format!("`/{}` **{}s**", command, seconds))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                };
+                let queue_limit = match queue_limit {
+                    Some(limit) => format!(
+                        "**{}** {} **{}s**",
+                        limit.commands,
+                        tr(COOLDOWNS_QUEUE_EVERY),
+                        limit.window
+                    ),
+                    None => tr(COOLDOWNS_NONE).to_string(),
+                };
+                f.write_str(&format!(
+                    "{}\n{} {}\n{} {}",
+                    tr(COOLDOWNS_TITLE),
+                    tr(COOLDOWNS_COMMANDS),
+                    cooldowns,
+                    tr(COOLDOWNS_QUEUE),
+                    queue_limit
+                ))
+            }
+            Self::DjRoleCleared => f.write_str(tr(DJ_ROLE_CLEARED)),
+            Self::DjRoleSet { role } => f.write_str(&format!("{} {}!", tr(DJ_ROLE_SET), role)),
+            Self::EmptyPauseOff => f.write_str(tr(EMPTY_PAUSE_OFF)),
+            Self::EmptyPauseOn => f.write_str(tr(EMPTY_PAUSE_ON)),
+            Self::Error => f.write_str(tr(ERROR)),
+            Self::Filters { filter } => {
+                let pattern = |pattern: &Option<String>| match pattern {
+                    Some(pattern) => format!("`{}`", pattern),
+                    None => tr(FILTERS_NONE).to_string(),
+                };
+                let state = |enabled: bool| match enabled {
+                    true => tr(BROADCAST_ON),
+                    false => tr(BROADCAST_OFF),
+                };
+                let max_duration = match filter.max_duration {
+                    Some(seconds) => {
+                        get_human_readable_timestamp(Some(Duration::from_secs(seconds)))
+                    }
+                    None => tr(FILTERS_NONE).to_string(),
+                };
+                f.write_str(&format!(
+                    "{}\n{} {}\n{} {}\n{} **{}**\n{} **{}**\n{} **{}**",
+                    tr(FILTERS_TITLE),
+                    tr(FILTERS_TITLE_PATTERN),
+                    pattern(&filter.title_pattern),
+                    tr(FILTERS_CHANNEL_PATTERN),
+                    pattern(&filter.channel_pattern),
+                    tr(FILTERS_LIVESTREAMS),
+                    state(filter.block_livestreams),
+                    tr(FILTERS_AGE_RESTRICTED),
+                    state(filter.block_age_restricted),
+                    tr(FILTERS_MAX_DURATION),
+                    max_duration
+                ))
+            }
+            Self::IdleAnnounceOff => f.write_str(tr(IDLE_ANNOUNCE_OFF)),
+            Self::IdleAnnounceOn { channel } => match channel {
+                Some(channel) => f.write_str(&format!("{} {}!", tr(IDLE_ANNOUNCE_IN), channel)),
+                None => f.write_str(tr(IDLE_ANNOUNCE_ON)),
+            },
+            Self::IdleTimeout { timeout } => f.write_str(&format!(
+                "{} **{}s** {}",
+                tr(IDLE_TIMEOUT_PREFIX),
+                timeout,
+                tr(IDLE_TIMEOUT_SUFFIX)
+            )),
+            Self::IdleTimeoutNever => f.write_str(tr(IDLE_TIMEOUT_NEVER)),
+            Self::ImportCancelled => f.write_str(tr(IMPORT_CANCELLED)),
+            Self::Language { name } => match name {
+                Some(name) => f.write_str(&format!("{} **{}**!", tr(LANGUAGE_SET), name)),
+                None => f.write_str(tr(LANGUAGE_AUTO)),
+            },
+            Self::Leaving => f.write_str(tr(LEAVING)),
+            Self::LoopDisable => f.write_str(tr(LOOP_DISABLED)),
+            Self::LoopEnable => f.write_str(tr(LOOP_ENABLED)),
+            Self::Moved { mention } => f.write_str(&format!("{} **{}**!", tr(MOVING), mention)),
+            Self::MusicBanned { user, ban } => {
+                let restriction = match ban.scope {
+                    BanScope::All => tr(MUSIC_BANNED_ALL),
+                    BanScope::Queue => tr(MUSIC_BANNED_QUEUE),
+                };
+                f.write_str(&format!(
+                    "{} {}{}!",
+                    restriction,
+                    user,
+                    format_ban_expiry(ban)
+                ))
+            }
+            Self::MusicNotBanned { user } => {
+                f.write_str(&format!("{} {}!", tr(MUSIC_NOT_BANNED), user))
+            }
+            Self::MusicUnbanned { user } => {
+                f.write_str(&format!("{} {}!", tr(MUSIC_UNBANNED), user))
+            }
+            Self::NowPlaying => f.write_str(tr(QUEUE_NOW_PLAYING)),
+            Self::Pause => f.write_str(tr(PAUSED)),
+            Self::PlaylistConfirm { total } => f.write_str(&format!(
+                "{} **{}** {}",
+                tr(PLAYLIST_CONFIRM_PREFIX),
+                total,
+                tr(PLAYLIST_CONFIRM_SUFFIX)
+            )),
+            Self::PlaylistProgress { resolved, total } => f.write_str(&format!(
+                "{} **{}/{}**\n`{}`",
+                tr(PLAYLIST_LOADING),
+                resolved,
+                total,
+                create_progress_bar(*resolved, *total)
+            )),
+            Self::PlaylistQueued { report } => {
+                let title = match report.cancelled {
+                    true => tr(PLAYLIST_CANCELLED),
+                    false => tr(PLAY_PLAYLIST),
+                };
+                let mut text = format!("{} (**{}/{}**)", title, report.queued, report.total);
+
+                if !report.failures.is_empty() {
+                    text.push_str(&format!(
+                        "\n\n⚠️ **{} {}**",
+                        report.failures.len(),
+                        tr(PLAYLIST_FAILED_ENTRIES)
+                    ));
+                }
+
+                for (i, failure) in report.failures.iter().enumerate() {
+                    if i == PLAYLIST_FAILURES_SHOWN {
+                        let remaining = report.failures.len() - PLAYLIST_FAILURES_SHOWN;
+                        text.push_str(&format!("\n… {} {}", remaining, tr(PLAYLIST_FAILED_MORE)));
+                        break;
+                    }
+
+                    let entry: String =
+                        failure.entry.chars().take(PLAYLIST_ENTRY_MAX_LEN).collect();
+                    text.push_str(&format!("\n`{}.` {} • *{}*", i + 1, entry, failure.reason));
+                }
+
+                f.write_str(&text)
+            }
+            Self::PlayAllFailed => f.write_str(tr(PLAY_ALL_FAILED)),
+            Self::PlayDomainBanned { domain } => f.write_str(&format!(
+                "⚠️ **{}** {}",
+                domain,
+                tr(PLAY_FAILED_BLOCKED_DOMAIN)
+            )),
+            Self::Search => f.write_str(tr(SEARCHING)),
+            Self::RemoveMultiple => f.write_str(tr(REMOVED_QUEUE_MULTIPLE)),
+            Self::Resume => f.write_str(tr(RESUMED)),
+            Self::Shuffle => f.write_str(tr(SHUFFLED_SUCCESS)),
+            Self::Stop => f.write_str(tr(STOPPED)),
+            Self::VoteExpired { action } => f.write_str(&format!(
+                "{} {} {}",
+                tr(VOTE_EXPIRED_PREFIX),
+                action,
+                tr(VOTE_EXPIRED_SUFFIX)
+            )),
+            Self::VotePassed { outcome } => {
+                f.write_str(&format!("{}\n{}", tr(VOTE_PASSED), outcome))
+            }
+            Self::VotePercent { vote, percent } => f.write_str(&format!(
+                "{} **{}** {} **{}%** {}",
+                tr(VOTE_PERCENT_PREFIX),
+                vote,
+                tr(VOTE_PERCENT_MIDDLE),
+                percent,
+                tr(VOTE_PERCENT_SUFFIX)
+            )),
+            Self::VoteRemoved { title } => {
+                f.write_str(&format!("{}: **{}**", tr(REMOVED_QUEUE), title))
             }
-            Self::Search => f.write_str(SEARCHING),
-            Self::RemoveMultiple => f.write_str(REMOVED_QUEUE_MULTIPLE),
-            Self::Resume => f.write_str(RESUMED),
-            Self::Shuffle => f.write_str(SHUFFLED_SUCCESS),
-            Self::Stop => f.write_str(STOPPED),
             Self::VoteSkip { mention, missing } => f.write_str(&format!(
                 "{}{} {} {} {}",
-                SKIP_VOTE_EMOJI, mention, SKIP_VOTE_USER, missing, SKIP_VOTE_MISSING
+                tr(SKIP_VOTE_EMOJI),
+                mention,
+                tr(SKIP_VOTE_USER),
+                missing,
+                tr(SKIP_VOTE_MISSING)
+            )),
+            Self::VoteTally {
+                action,
+                votes,
+                required,
+                ends,
+            } => f.write_str(&format!(
+                "{} {}\n**{}/{}** {} <t:{}:R>",
+                tr(VOTE_TALLY_PREFIX),
+                action,
+                votes,
+                required,
+                tr(VOTE_TALLY_VOTES),
+                ends
             )),
-            Self::Seek { timestamp } => f.write_str(&format!("{} **{}**!", SEEKED, timestamp)),
-            Self::Skip => f.write_str(SKIPPED),
-            Self::SkipAll => f.write_str(SKIPPED_ALL),
+            Self::Seek { timestamp } => f.write_str(&format!("{} **{}**!", tr(SEEKED), timestamp)),
+            Self::Skip => f.write_str(tr(SKIPPED)),
+            Self::SkipAll => f.write_str(tr(SKIPPED_ALL)),
             Self::SkipTo { title, url } => {
-                f.write_str(&format!("{} [**{}**]({})!", SKIPPED_TO, title, url))
+                f.write_str(&format!("{} [**{}**]({})!", tr(SKIPPED_TO), title, url))
             }
-            Self::Summon { mention } => f.write_str(&format!("{} **{}**!", JOINING, mention)),
+            Self::StageRequestedToSpeak { mention } => f.write_str(&format!(
+                "{} **{}**!\n{}",
+                tr(JOINING),
+                mention,
+                tr(STAGE_REQUESTED_TO_SPEAK)
+            )),
+            Self::StageTopicOff => f.write_str(tr(STAGE_TOPIC_OFF)),
+            Self::StageTopicOn => f.write_str(tr(STAGE_TOPIC_ON)),
+            Self::Summon { mention } => f.write_str(&format!("{} **{}**!", tr(JOINING), mention)),
             Self::Version { current } => f.write_str(&format!(
                 "{} [{}]({}/tag/v{})\n{}({}/latest)",
-                VERSION, current, RELEASES_LINK, current, VERSION_LATEST, RELEASES_LINK
+                tr(VERSION),
+                current,
+                RELEASES_LINK,
+                current,
+                tr(VERSION_LATEST),
+                RELEASES_LINK
             )),
         }
     }
 }
+
+/// Renders when a ban runs out as a Discord timestamp, or nothing for permanent bans.
+pub fn format_ban_expiry(ban: &MusicBan) -> String {
+    ban.expires.map_or(String::new(), |expires| {
+        format!(" {} <t:{}:f>", tr(MUSIC_BAN_UNTIL), expires)
+    })
+}
@@ -1,5 +1,36 @@
+pub const ALWAYS_ON_DISABLED: &str = "🌙 24/7 mode disabled, I will come and go as usual!";
+pub const ALWAYS_ON_ENABLED: &str = "🌞 24/7 mode enabled, I will stay in";
+pub const ALWAYS_ON_PLAYLIST: &str = "🔁 Looping playlist";
+pub const AUDIT_LOG_CLEARED: &str = "🛡️ Commands will no longer be logged!";
+pub const AUDIT_LOG_SET: &str = "🛡️ Destructive and admin commands will be logged in";
+pub const AUDIT_MORE_TRACKS: &str = "more";
+pub const AUDIT_NO_CHANGES: &str = "Nothing changed";
+pub const AUDIT_REMOVED_TRACKS: &str = "Removed tracks";
+pub const AUDIT_REORDERED: &str = "Reordered tracks";
+pub const AUDIT_SETTINGS_CHANGED: &str = "Changed settings";
+pub const AUDIT_UNKNOWN_TRACK: &str = "Unknown";
+pub const AUDIT_USED: &str = "used";
+pub const AUTOLEAVE_ALERT: &str = "Everyone left the voice channel, so I'll leave for now to save resources.\nFeel free to summon me back any time!";
+pub const AUTOLEAVE_GRACE_PREFIX: &str = "I'll leave";
+pub const AUTOLEAVE_GRACE_SUFFIX: &str = "after everyone else has left.";
+pub const AUTOLEAVE_OFF: &str = "🤖 Auto-leave OFF!";
+pub const AUTOLEAVE_ON: &str = "🤖 Auto-leave ON!";
 pub const AUTOPAUSE_OFF: &str = "🤖 Autopause OFF!";
 pub const AUTOPAUSE_ON: &str = "🤖 Autopause ON!";
+pub const BROADCAST_NICKNAME: &str = "Nickname:";
+pub const BROADCAST_OFF: &str = "OFF";
+pub const BROADCAST_ON: &str = "ON";
+pub const BROADCAST_PRESENCE: &str = "Activity:";
+pub const BROADCAST_STATUS: &str = "Voice channel status:";
+pub const BROADCAST_TITLE: &str = "📡 Now playing broadcast";
+pub const CACHE_STATS_ENTRIES: &str = "Entries:";
+pub const CACHE_STATS_HITS: &str = "Hits:";
+pub const CACHE_STATS_MISSES: &str = "Misses:";
+pub const CACHE_STATS_TITLE: &str = "🗄️ **Metadata cache**";
+pub const CHANNELS_ANY: &str = "any channel";
+pub const CHANNELS_TEXT: &str = "Commands:";
+pub const CHANNELS_TITLE: &str = "📌 Allowed channels";
+pub const CHANNELS_VOICE: &str = "Voice:";
 pub const CLEARED: &str = "🗑️ Cleared!";
 
 pub const DOMAIN_FORM_ALLOWED_TITLE: &str = "Allowed domains";
@@ -10,25 +41,130 @@ pub const DOMAIN_FORM_BANNED_PLACEHOLDER: &str =
     "Add domains separated by \';\'. If left blank, all (except for allowed) are blocked by default.";
 pub const DOMAIN_FORM_TITLE: &str = "Manage sources";
 
+pub const COOLDOWNS_COMMANDS: &str = "Per-member cooldowns:";
+pub const COOLDOWNS_NONE: &str = "none";
+pub const COOLDOWNS_QUEUE: &str = "Queue changes:";
+pub const COOLDOWNS_QUEUE_EVERY: &str = "every";
+pub const COOLDOWNS_TITLE: &str = "⏳ Cooldowns";
+pub const DJ_ROLE_CLEARED: &str = "🎧 Everyone can manage the queue again!";
+pub const DJ_ROLE_SET: &str = "🎧 Managing the queue is now reserved for";
+pub const EMPTY_PAUSE_OFF: &str = "🤖 Pause while nobody is listening OFF!";
+pub const EMPTY_PAUSE_ON: &str = "🤖 Pause while nobody is listening ON!";
 pub const ERROR: &str = "Fatality! Something went wrong ☹️";
 pub const FAIL_ALREADY_HERE: &str = "⚠️ I'm already here!";
 pub const FAIL_ANOTHER_CHANNEL: &str = "⚠️ I'm already connected to";
 pub const FAIL_AUTHOR_DISCONNECTED: &str = "⚠️ You are not connected to";
 pub const FAIL_AUTHOR_NOT_FOUND: &str = "⚠️ Could not find you in any voice channel!";
+pub const FAIL_CHANNEL_NOT_CACHED: &str = "Channel not found in cache";
+pub const FAIL_COMMAND_ADMIN_ONLY: &str = "⚠️ Only admins can use this command here!";
+pub const FAIL_COMMAND_DISABLED: &str = "⚠️ This command is disabled in this server!";
+pub const FAIL_COMMAND_DJ_ONLY: &str = "⚠️ Only DJs can use this command here!";
+pub const FAIL_FETCH_PLAYLIST: &str = "failed to fetch playlist";
+pub const FAIL_GUILD_CACHE: &str = "Guild cache not initialized";
+pub const FAIL_GUILD_NOT_CACHED: &str = "Guild not found in cache";
+pub const FAIL_GUILD_ONLY: &str = "This command can only be used in a server";
+pub const FAIL_GUILD_SETTINGS: &str = "Guild settings not initialized";
+pub const FAIL_INVALID_DOMAINS: &str = "⚠️ These are not valid domains:";
+pub const FAIL_JOIN_CHANNEL: &str = "Failed to join channel:";
+pub const FAIL_LEAVE: &str = "Failed to leave voice channel";
 pub const FAIL_LOOP: &str = "⚠️ Failed to toggle loop!";
 pub const FAIL_MINUTES_PARSING: &str = "⚠️ Invalid formatting for 'minutes'";
+pub const FAIL_MISSING_CHANNEL: &str = "Missing channel argument";
+pub const FAIL_MISSING_QUERY: &str = "Missing query argument";
+pub const FAIL_MISSING_SUBCOMMAND: &str = "Missing subcommand";
+pub const FAIL_MISSING_TIMEOUT: &str = "Missing timeout argument";
+pub const FAIL_MISSING_USER: &str = "Missing user";
+pub const FAIL_MISSING_VOTE_PERCENT: &str = "Missing vote percentage";
+pub const FAIL_MUSIC_BANNED: &str = "⛔ You are banned from using the bot here";
+pub const FAIL_MUSIC_BANNED_QUEUE: &str = "⛔ You are banned from adding tracks here";
+pub const FAIL_MUSIC_BAN_PROTECTED: &str = "⚠️ Admins and bots can't be banned from the bot!";
+pub const FAIL_NOT_DJ: &str =
+    "⚠️ Only DJs, the only listener or whoever queued these tracks can do that!";
+pub const FAIL_NOT_IN_RANGE: &str = "should be between";
+pub const FAIL_NOT_IN_RANGE_AND: &str = "and";
+pub const FAIL_NOT_IN_RANGE_VALUE: &str = "but was";
+pub const FAIL_NO_IMPORTS: &str = "⚠️ There is no playlist being imported!";
 pub const FAIL_NO_SONG_ON_INDEX: &str = "⚠️ There is no queued song on that index!";
 pub const FAIL_NO_VOICE_CONNECTION: &str = "⚠️ I'm not connected to any voice channel!";
+pub const FAIL_PAUSE: &str = "Failed to pause";
+pub const FAIL_PLAYLIST_RANGE: &str = "⚠️ `end` can't be lower than `start`!";
+pub const FAIL_QUERY_PARSING: &str = "Something went wrong while parsing your query!";
+pub const FAIL_RATE_LIMITED: &str = "⏳ Slow down! Try again in";
 pub const FAIL_REMOVE_RANGE: &str = "⚠️ `until` needs to be higher than `index`!";
+pub const FAIL_RESUME: &str = "Failed resuming track";
+pub const FAIL_ROTATE_QUEUE: &str = "cannot rotate queues smaller than 3 tracks";
 pub const FAIL_SECONDS_PARSING: &str = "⚠️ Invalid formatting for 'seconds'";
+pub const FAIL_SPOTIFY_ALBUM: &str = "failed to fetch album";
+pub const FAIL_SPOTIFY_ALBUM_ID: &str = "album ID contains invalid characters";
+pub const FAIL_SPOTIFY_AUTH: &str = "no auth attempts";
+pub const FAIL_SPOTIFY_CLIENT_ID: &str = "missing spotify client ID";
+pub const FAIL_SPOTIFY_CLIENT_SECRET: &str = "missing spotify client secret";
+pub const FAIL_SPOTIFY_PLAYLIST_ID: &str = "playlist ID contains invalid characters";
+pub const FAIL_SPOTIFY_TRACK: &str = "failed to fetch track";
+pub const FAIL_SPOTIFY_TRACK_ID: &str = "track ID contains invalid characters";
+pub const FAIL_STAGE_SPEAKER: &str =
+    "⚠️ I need the **Mute Members** or **Request to Speak** permission to be heard on this stage!";
+pub const FAIL_TEXT_CHANNEL_NOT_ALLOWED: &str = "⚠️ Music commands can only be used in";
+pub const FAIL_TRACK_FILTERED_PREFIX: &str = "⛔ This track is blocked by the";
+pub const FAIL_TRACK_FILTERED_SUFFIX: &str = "rule!";
+pub const FAIL_TRACK_INFO: &str = "Failed to get track info";
+pub const FAIL_UNKNOWN_COMMAND: &str = "⚠️ There is no such command:";
+pub const FAIL_VOICE_CALL: &str = "Failed to get voice call";
+pub const FAIL_VOICE_CHANNEL_NOT_ALLOWED: &str = "⚠️ I can only join";
+pub const FAIL_VOICE_MANAGER: &str = "Voice manager not configured";
+pub const FAIL_VOTE_DEAFENED: &str = "⚠️ You can't vote while deafened!";
+pub const FAIL_VOTE_IN_PROGRESS: &str = "⚠️ There's already a vote on that, use the button on it!";
+pub const FAIL_VOTE_TRACK_GONE: &str = "⚠️ That track is no longer in the queue!";
 pub const FAIL_WRONG_CHANNEL: &str = "⚠️ We are not in the same voice channel!";
+pub const FILTERS_AGE_RESTRICTED: &str = "Block age-restricted:";
+pub const FILTERS_CHANNEL_PATTERN: &str = "Channel keywords:";
+pub const FILTERS_LIVESTREAMS: &str = "Block livestreams:";
+pub const FILTERS_MAX_DURATION: &str = "Maximum duration:";
+pub const FILTERS_NONE: &str = "none";
+pub const FILTERS_TITLE: &str = "⛔ Content filters";
+pub const FILTERS_TITLE_PATTERN: &str = "Title keywords:";
+pub const FILTER_RULE_AGE_RESTRICTED: &str = "age restriction";
+pub const FILTER_RULE_CHANNEL: &str = "channel keyword";
+pub const FILTER_RULE_LIVESTREAM: &str = "livestream";
+pub const FILTER_RULE_MAX_DURATION: &str = "maximum duration";
+pub const FILTER_RULE_TITLE: &str = "title keyword";
 pub const IDLE_ALERT: &str = "I've been idle for a while, so I'll leave for now to save resources.\nFeel free to summon me back any time!";
+pub const IDLE_ANNOUNCE_IN: &str = "📢 Idle departures will be announced in";
+pub const IDLE_ANNOUNCE_OFF: &str = "🔕 Idle departures will no longer be announced!";
+pub const IDLE_ANNOUNCE_ON: &str =
+    "📢 Idle departures will be announced where I was summoned from!";
+pub const IDLE_TIMEOUT_NEVER: &str = "⏲️ I will no longer leave when idle!";
+pub const IDLE_TIMEOUT_PREFIX: &str = "⏲️ I will leave after";
+pub const IDLE_TIMEOUT_SUFFIX: &str = "of inactivity!";
+pub const IMPORT_CANCELLED: &str = "⏹️ Cancelled playlist import!";
 pub const JOINING: &str = "Joining";
+pub const LANGUAGE_AUTO: &str = "🌐 I'll now speak each member's own Discord language!";
+pub const LANGUAGE_SET: &str = "🌐 I'll now speak";
 pub const LEAVING: &str = "👋 See you soon!";
 pub const LOOP_DISABLED: &str = "🔁 Disabled loop!";
 pub const LOOP_ENABLED: &str = "🔁 Enabled loop!";
+pub const MOVING: &str = "Moving to";
+pub const MUSIC_BANNED_ALL: &str = "🔨 Banned from using the bot:";
+pub const MUSIC_BANNED_QUEUE: &str = "🔨 Banned from adding tracks:";
+pub const MUSIC_BAN_UNTIL: &str = "until";
+pub const MUSIC_NOT_BANNED: &str = "⚠️ There is no ban on";
+pub const MUSIC_UNBANNED: &str = "🕊️ Lifted the ban on";
 pub const NOTHING_IS_PLAYING: &str = "🔈 Nothing is playing!";
 pub const PAUSED: &str = "⏸️ Paused!";
+pub const PERMISSIONS_EMPTY: &str = "Every command follows its default behavior.";
+pub const PERMISSIONS_EXPIRED: &str =
+    "⏲️ This permissions menu has expired, use `/permissions` again to make further changes.";
+pub const PERMISSIONS_PICK_COMMAND: &str = "Pick a command to change who can use it";
+pub const PERMISSIONS_PICK_POLICY: &str = "Who should be able to use";
+pub const PERMISSIONS_TITLE: &str = "🔐 Command permissions";
+pub const PLAYLIST_CANCELLED: &str = "⏹️ Playlist import cancelled!";
+pub const PLAYLIST_CANCEL_BUTTON: &str = "Cancel";
+pub const PLAYLIST_CONFIRM_BUTTON: &str = "Import";
+pub const PLAYLIST_CONFIRM_PREFIX: &str = "📃 This playlist has";
+pub const PLAYLIST_CONFIRM_SUFFIX: &str = "tracks, are you sure you want to add all of them?";
+pub const PLAYLIST_FAILED_ENTRIES: &str = "track(s) could not be added:";
+pub const PLAYLIST_FAILED_MORE: &str = "more";
+pub const PLAYLIST_LOADING: &str = "📥 Loading playlist...";
 pub const PLAY_FAILED_BLOCKED_DOMAIN: &str =
     "**is either not allowed in this server or is not supported!** \n\nTo explicitely allow this domain, ask a moderator to run the `/managesources` command. [Click to see a list of supported sources.](https://github.com/yt-dlp/yt-dlp/blob/master/supportedsites.md)";
 pub const PLAY_ALL_FAILED: &str =
@@ -36,6 +172,11 @@ pub const PLAY_ALL_FAILED: &str =
 pub const PLAY_PLAYLIST: &str = "📃 Added playlist to queue!";
 pub const PLAY_QUEUE: &str = "📃 Added to queue!";
 pub const PLAY_TOP: &str = "📃 Added to top!";
+pub const POLICY_ADMIN: &str = "Admins";
+pub const POLICY_DEFAULT: &str = "Default";
+pub const POLICY_DISABLED: &str = "Disabled";
+pub const POLICY_DJ: &str = "DJs";
+pub const POLICY_EVERYONE: &str = "Everyone";
 pub const QUEUE_EXPIRED: &str =
     "In order to save resources, this command has expired.\nPlease feel free to reinvoke it!";
 pub const QUEUE_IS_EMPTY: &str = "Queue is empty!";
@@ -51,6 +192,7 @@ pub const RESUMED: &str = "▶️ Resumed!";
 pub const SEARCHING: &str = "🔎 Searching...";
 pub const SEEKED: &str = "⏩ Seeked current track to";
 pub const SHUFFLED_SUCCESS: &str = "🔀 Shuffled successfully!";
+pub const SKIP_VOTE_BUTTON: &str = "Vote to skip";
 pub const SKIP_VOTE_EMOJI: &str = "🗳 ";
 pub const SKIP_VOTE_MISSING: &str = "more vote(s) needed to skip!";
 pub const SKIP_VOTE_USER: &str = "has voted to skip!";
@@ -61,10 +203,35 @@ pub const SPOTIFY_AUTH_FAILED: &str = "⚠️ **Could not authenticate with Sp
 pub const SPOTIFY_INVALID_QUERY: &str =
     "⚠️ **Could not find any tracks with that link!**\nAre you sure that is a valid Spotify URL?";
 pub const SPOTIFY_PLAYLIST_FAILED: &str = "⚠️ **Failed to fetch playlist!**\nIt's likely that this playlist is either private or a personalized recommendation playlist generated by Spotify.";
+pub const STAGE_REQUESTED_TO_SPEAK: &str =
+    "✋ I have asked to speak, a stage moderator needs to invite me up.";
+pub const STAGE_TOPIC_OFF: &str = "🎙️ Stage topic updates OFF!";
+pub const STAGE_TOPIC_ON: &str = "🎙️ Stage topic updates ON!";
 pub const STOPPED: &str = "⏹️ Stopped!";
 pub const TRACK_DURATION: &str = "Track duration: ";
 pub const TRACK_NOT_FOUND: &str = "⚠️ **Could not play track!**\nYour request yielded no results.";
 pub const TRACK_INAPPROPRIATE: &str = "⚠️ **Could not play track!**\nThe video you requested may be inappropriate for some users, so sign-in is required.";
 pub const TRACK_TIME_TO_PLAY: &str = "Estimated time until play: ";
+pub const UNAVAILABLE_AGE_RESTRICTED: &str = "age-restricted";
+pub const UNAVAILABLE_DELETED: &str = "deleted or unavailable";
+pub const UNAVAILABLE_FILTERED: &str = "blocked by the";
+pub const UNAVAILABLE_FILTERED_SUFFIX: &str = "rule";
+pub const UNAVAILABLE_GEO_BLOCKED: &str = "not available in this region";
+pub const UNAVAILABLE_NOT_FOUND: &str = "no results found";
+pub const UNAVAILABLE_OTHER: &str = "could not be resolved";
+pub const UNAVAILABLE_PRIVATE: &str = "private video";
 pub const VERSION_LATEST: &str = "Find the latest version [here]";
 pub const VERSION: &str = "Version";
+pub const VOTE_BUTTON: &str = "🗳 Vote";
+pub const VOTE_CLEAR: &str = "clear the queue";
+pub const VOTE_EXPIRED_PREFIX: &str = "⏲️ The vote to";
+pub const VOTE_EXPIRED_SUFFIX: &str = "has expired!";
+pub const VOTE_PASSED: &str = "✅ The vote passed!";
+pub const VOTE_PERCENT_MIDDLE: &str = "now takes";
+pub const VOTE_PERCENT_PREFIX: &str = "🗳 Voting to";
+pub const VOTE_PERCENT_SUFFIX: &str = "of the listeners!";
+pub const VOTE_REMOVE: &str = "remove";
+pub const VOTE_SHUFFLE: &str = "shuffle the queue";
+pub const VOTE_STOP: &str = "stop the music";
+pub const VOTE_TALLY_PREFIX: &str = "🗳 Vote to";
+pub const VOTE_TALLY_VOTES: &str = "votes, ends";
@@ -1,2 +1,3 @@
+pub mod i18n;
 pub mod message;
 pub mod messages;
@@ -0,0 +1,332 @@
+use crate::{errors::ParrotError, utils::Debouncer};
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use songbird::input::AuxMetadata;
+use std::{
+    collections::HashMap,
+    env,
+    fs::{create_dir_all, rename, OpenOptions},
+    io::{BufReader, BufWriter},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tokio::{sync::Mutex, task::spawn_blocking};
+
+const DEFAULT_CACHE_PATH: &str = "data/metadata_cache.json";
+const DEFAULT_CACHE_TTL_SECS: u64 = 60 * 60 * 24 * 7;
+const DEFAULT_CACHE_CAPACITY: usize = 10_000;
+const CACHE_SAVE_INTERVAL: Duration = Duration::from_secs(30);
+
+lazy_static! {
+    pub static ref METADATA_CACHE: Mutex<MetadataCache> = Mutex::new(MetadataCache::from_env());
+    static ref SAVE_LOCK: Mutex<()> = Mutex::new(());
+}
+
+/// Serializable mirror of songbird's [`AuxMetadata`], which doesn't implement serde's traits.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CachedMetadata {
+    pub track: Option<String>,
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    pub date: Option<String>,
+    pub channels: Option<u8>,
+    pub channel: Option<String>,
+    pub start_time: Option<Duration>,
+    pub duration: Option<Duration>,
+    pub sample_rate: Option<u32>,
+    pub source_url: Option<String>,
+    pub title: Option<String>,
+    pub thumbnail: Option<String>,
+}
+
+impl From<&AuxMetadata> for CachedMetadata {
+    fn from(metadata: &AuxMetadata) -> Self {
+        Self {
+            track: metadata.track.clone(),
+            artist: metadata.artist.clone(),
+            album: metadata.album.clone(),
+            date: metadata.date.clone(),
+            channels: metadata.channels,
+            channel: metadata.channel.clone(),
+            start_time: metadata.start_time,
+            duration: metadata.duration,
+            sample_rate: metadata.sample_rate,
+            source_url: metadata.source_url.clone(),
+            title: metadata.title.clone(),
+            thumbnail: metadata.thumbnail.clone(),
+        }
+    }
+}
+
+impl From<CachedMetadata> for AuxMetadata {
+    fn from(metadata: CachedMetadata) -> Self {
+        Self {
+            track: metadata.track,
+            artist: metadata.artist,
+            album: metadata.album,
+            date: metadata.date,
+            channels: metadata.channels,
+            channel: metadata.channel,
+            start_time: metadata.start_time,
+            duration: metadata.duration,
+            sample_rate: metadata.sample_rate,
+            source_url: metadata.source_url,
+            title: metadata.title,
+            thumbnail: metadata.thumbnail,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct CacheEntry {
+    metadata: CachedMetadata,
+    url: String,
+    age_limit: Option<u64>,
+    stored_at: u64,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CacheStats {
+    pub entries: usize,
+    pub capacity: usize,
+    pub hits: u64,
+    pub misses: u64,
+}
+
+/// Remembers the metadata and resolved video URL of previous lookups so that the same
+/// URLs and search queries don't have to go through yt-dlp again.
+/// Entries expire after a TTL, the oldest ones are evicted once the capacity is exceeded
+/// and, when given a path, the cache is persisted to disk between restarts.
+pub struct MetadataCache {
+    entries: HashMap<String, CacheEntry>,
+    path: Option<PathBuf>,
+    ttl: Duration,
+    capacity: usize,
+    hits: u64,
+    misses: u64,
+    dirty: bool,
+    saver: Debouncer,
+}
+
+impl MetadataCache {
+    pub fn new(path: Option<PathBuf>, ttl: Duration, capacity: usize) -> MetadataCache {
+        MetadataCache {
+            entries: HashMap::new(),
+            path,
+            ttl,
+            capacity,
+            hits: 0,
+            misses: 0,
+            dirty: false,
+            saver: Debouncer::new(CACHE_SAVE_INTERVAL),
+        }
+    }
+
+    fn from_env() -> MetadataCache {
+        let path = env::var("CACHE_PATH").unwrap_or(DEFAULT_CACHE_PATH.to_string());
+
+        let ttl = env::var("CACHE_TTL")
+            .ok()
+            .and_then(|ttl| ttl.parse().ok())
+            .unwrap_or(DEFAULT_CACHE_TTL_SECS);
+
+        let capacity = env::var("CACHE_SIZE")
+            .ok()
+            .and_then(|size| size.parse().ok())
+            .unwrap_or(DEFAULT_CACHE_CAPACITY);
+
+        MetadataCache::new(Some(path.into()), Duration::from_secs(ttl), capacity)
+    }
+
+    pub fn url_key(url: &str) -> String {
+        format!("url:{}", url.trim())
+    }
+
+    /// Search queries are compared case-insensitively and regardless of spacing.
+    pub fn query_key(query: &str) -> String {
+        let normalized = query
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase();
+
+        format!("query:{}", normalized)
+    }
+
+    /// Returns the cached metadata, resolved video URL and age limit for a key, if still fresh.
+    pub fn get(&mut self, key: &str) -> Option<(AuxMetadata, String, Option<u64>)> {
+        let now = unix_now();
+        let entry = self
+            .entries
+            .get(key)
+            .filter(|entry| now.saturating_sub(entry.stored_at) < self.ttl.as_secs())
+            .cloned();
+
+        match entry {
+            Some(entry) => {
+                self.hits += 1;
+                Some((entry.metadata.into(), entry.url, entry.age_limit))
+            }
+            None => {
+                self.entries.remove(key);
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    pub fn insert(
+        &mut self,
+        keys: &[String],
+        metadata: &AuxMetadata,
+        url: &str,
+        age_limit: Option<u64>,
+    ) {
+        let entry = CacheEntry {
+            metadata: metadata.into(),
+            url: url.to_string(),
+            age_limit,
+            stored_at: unix_now(),
+        };
+
+        for key in keys {
+            self.entries.insert(key.clone(), entry.clone());
+        }
+
+        self.evict();
+        self.dirty = true;
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            entries: self.entries.len(),
+            capacity: self.capacity,
+            hits: self.hits,
+            misses: self.misses,
+        }
+    }
+
+    fn evict(&mut self) {
+        let now = unix_now();
+        let ttl = self.ttl.as_secs();
+        self.entries
+            .retain(|_, entry| now.saturating_sub(entry.stored_at) < ttl);
+
+        if self.entries.len() <= self.capacity {
+            return;
+        }
+
+        let mut by_age: Vec<(String, u64)> = self
+            .entries
+            .iter()
+            .map(|(key, entry)| (key.clone(), entry.stored_at))
+            .collect();
+        by_age.sort_by_key(|(_, stored_at)| *stored_at);
+
+        let excess = self.entries.len() - self.capacity;
+        for (key, _) in by_age.into_iter().take(excess) {
+            self.entries.remove(&key);
+        }
+    }
+
+    /// Adds the entries persisted on disk, keeping any that were stored since.
+    fn restore(&mut self, entries: HashMap<String, CacheEntry>) {
+        for (key, entry) in entries {
+            self.entries.entry(key).or_insert(entry);
+        }
+        self.evict();
+    }
+
+    /// Copies the entries to write to disk if they changed since the last save.
+    /// Writing the whole cache for every lookup of a playlist would be wasteful,
+    /// so unless forced this happens at most once per save interval.
+    fn snapshot(&mut self, force: bool) -> Option<(PathBuf, HashMap<String, CacheEntry>)> {
+        let path = self.path.clone()?;
+        if !self.dirty || !(force || self.saver.ready()) {
+            return None;
+        }
+
+        self.dirty = false;
+        Some((path, self.entries.clone()))
+    }
+}
+
+/// Loads the cache persisted on disk, reading it outside of the async runtime.
+pub async fn load_metadata_cache() {
+    let Some(path) = METADATA_CACHE.lock().await.path.clone() else {
+        return;
+    };
+
+    match spawn_blocking(move || read_entries(&path)).await {
+        Ok(Ok(Some(entries))) => METADATA_CACHE.lock().await.restore(entries),
+        Ok(Ok(None)) => {}
+        Ok(Err(err)) => eprintln!("[WARN] Failed to load metadata cache: {}", err),
+        Err(err) => eprintln!("[WARN] Failed to load metadata cache: {}", err),
+    }
+}
+
+/// Persists the cache if it changed, writing it outside of the async runtime.
+/// Forcing the save skips the interval between saves, e.g. when shutting down.
+pub async fn save_metadata_cache(force: bool) {
+    // keeps an overdue save and the one at shutdown from writing the same file at once
+    let _guard = SAVE_LOCK.lock().await;
+
+    let Some((path, entries)) = METADATA_CACHE.lock().await.snapshot(force) else {
+        return;
+    };
+
+    match spawn_blocking(move || write_entries(&path, &entries)).await {
+        Ok(Ok(())) => {}
+        Ok(Err(err)) => eprintln!("[WARN] Failed to save metadata cache: {}", err),
+        Err(err) => eprintln!("[WARN] Failed to save metadata cache: {}", err),
+    }
+}
+
+fn read_entries(path: &Path) -> Result<Option<HashMap<String, CacheEntry>>, ParrotError> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let file = OpenOptions::new().read(true).open(path)?;
+    let reader = BufReader::new(file);
+    let entries: HashMap<String, Value> = serde_json::from_reader(reader)?;
+
+    // entries cached before age limits were recorded are resolved again
+    let entries = entries
+        .into_iter()
+        .filter(|(_, entry)| entry.get("age_limit").is_some())
+        .filter_map(|(key, entry)| Some((key, serde_json::from_value(entry).ok()?)))
+        .collect();
+
+    Ok(Some(entries))
+}
+
+fn write_entries(path: &Path, entries: &HashMap<String, CacheEntry>) -> Result<(), ParrotError> {
+    if let Some(parent) = path.parent().filter(|p| p != &Path::new("")) {
+        create_dir_all(parent)?;
+    }
+    let temp_path = path.with_extension("json.tmp");
+
+    let file = OpenOptions::new()
+        .write(true)
+        .truncate(true)
+        .create(true)
+        .open(&temp_path)?;
+
+    let mut writer = BufWriter::new(file);
+    serde_json::to_writer(&mut writer, entries)?;
+
+    let file = writer.into_inner().map_err(|e| e.into_error())?;
+    file.sync_all()?;
+
+    rename(&temp_path, path)?;
+    Ok(())
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
@@ -0,0 +1,176 @@
+use reqwest::{header::LOCATION, redirect::Policy, Client};
+use std::{net::IpAddr, sync::OnceLock, time::Duration};
+use tokio::net::lookup_host;
+use url::{Host, Url};
+
+use crate::utils::compare_domains;
+
+/// Hosts that only redirect to the actual media, resolved before any domain check.
+const SHORT_LINK_HOSTS: [&str; 7] = [
+    "spotify.link",
+    "spotify.app.link",
+    "on.soundcloud.com",
+    "bit.ly",
+    "tinyurl.com",
+    "t.co",
+    "goo.gl",
+];
+
+const MAX_REDIRECTS: usize = 5;
+const RESOLVE_TIMEOUT: u64 = 5;
+
+static REDIRECT_CLIENT: OnceLock<Client> = OnceLock::new();
+
+fn get_redirect_client() -> &'static Client {
+    REDIRECT_CLIENT.get_or_init(|| {
+        Client::builder()
+            .redirect(Policy::none())
+            .timeout(Duration::from_secs(RESOLVE_TIMEOUT))
+            .build()
+            .unwrap_or_default()
+    })
+}
+
+fn is_short_link(url: &Url) -> bool {
+    url.host_str().is_some_and(|host| {
+        SHORT_LINK_HOSTS
+            .iter()
+            .any(|short| compare_domains(short, host))
+    })
+}
+
+/// Whether an address belongs to the public internet rather than to the bot's own network.
+pub fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [a, b, ..] = ip.octets();
+            let shared = a == 100 && (64..128).contains(&b);
+            !(ip.is_private()
+                || ip.is_loopback()
+                || ip.is_link_local()
+                || ip.is_broadcast()
+                || ip.is_documentation()
+                || ip.is_unspecified()
+                || shared)
+        }
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_ip(ip.into()),
+            None => {
+                let unique_local = ip.segments()[0] & 0xfe00 == 0xfc00;
+                let link_local = ip.segments()[0] & 0xffc0 == 0xfe80;
+                !(ip.is_loopback() || ip.is_unspecified() || unique_local || link_local)
+            }
+        },
+    }
+}
+
+/// Whether every address the URL's host resolves to is public.
+async fn is_public_host(url: &Url) -> bool {
+    let port = url.port_or_known_default().unwrap_or(443);
+    match url.host() {
+        Some(Host::Ipv4(ip)) => is_public_ip(ip.into()),
+        Some(Host::Ipv6(ip)) => is_public_ip(ip.into()),
+        Some(Host::Domain(domain)) => match lookup_host((domain, port)).await {
+            Ok(addrs) => {
+                let addrs: Vec<_> = addrs.collect();
+                !addrs.is_empty() && addrs.iter().all(|addr| is_public_ip(addr.ip()))
+            }
+            Err(_) => false,
+        },
+        None => false,
+    }
+}
+
+/// Follows the redirects of known link shorteners, leaving any other URL untouched.
+/// Redirects are followed one hop at a time and only while they stay on a public
+/// link shortener, so a short link can't make the bot request anything else.
+/// If the short link can't be resolved, it is returned as is.
+pub async fn resolve_short_link(url: Url) -> Url {
+    if !is_short_link(&url) {
+        return url;
+    }
+
+    let mut current = url.clone();
+    for _ in 0..MAX_REDIRECTS {
+        if !is_short_link(&current) {
+            return current;
+        }
+
+        if !matches!(current.scheme(), "http" | "https") || !is_public_host(&current).await {
+            eprintln!(
+                "[WARN] Refusing to resolve short link {} through {}",
+                url, current
+            );
+            return url;
+        }
+
+        let response = match get_redirect_client().head(current.clone()).send().await {
+            Ok(response) => response,
+            Err(err) => {
+                eprintln!("[WARN] Failed to resolve short link {}: {}", url, err);
+                return url;
+            }
+        };
+
+        let location = response
+            .headers()
+            .get(LOCATION)
+            .and_then(|location| location.to_str().ok())
+            .filter(|_| response.status().is_redirection())
+            .and_then(|location| current.join(location).ok());
+
+        match location {
+            Some(location) => current = location,
+            None => return current,
+        }
+    }
+
+    current
+}
+
+/// Rewrites alternative hosts of the supported sources to their canonical form,
+/// so the same media always ends up with the same URL.
+pub fn normalize_url(mut url: Url) -> Url {
+    let Some(host) = url
+        .host_str()
+        .map(|host| host.trim_end_matches('.').to_string())
+    else {
+        return url;
+    };
+
+    match host.as_str() {
+        "youtube.com" | "m.youtube.com" | "music.youtube.com" => {
+            let _ = url.set_host(Some("www.youtube.com"));
+        }
+        "youtu.be" => {
+            let id = url.path().trim_start_matches('/').to_string();
+            let mut query = format!("v={}", id);
+            if let Some(rest) = url.query().filter(|rest| !rest.is_empty()) {
+                query = format!("{}&{}", query, rest);
+            }
+
+            if let Ok(watch) = Url::parse("https://www.youtube.com/watch") {
+                url = watch;
+                url.set_query(Some(&query));
+            }
+        }
+        "spotify.com" | "play.spotify.com" | "open.spotify.com" => {
+            let _ = url.set_host(Some("open.spotify.com"));
+
+            // localized links carry an extra segment, e.g. /intl-de/track/<id>
+            let path = url.path().to_string();
+            let mut segments = path.split('/').filter(|segment| !segment.is_empty());
+            if segments
+                .clone()
+                .next()
+                .is_some_and(|segment| segment.starts_with("intl-"))
+            {
+                segments.next();
+                url.set_path(&segments.collect::<Vec<_>>().join("/"));
+            }
+        }
+        _ => {}
+    }
+
+    url
+}
@@ -1,2 +1,4 @@
+pub mod cache;
+pub mod links;
 pub mod spotify;
 pub mod youtube;
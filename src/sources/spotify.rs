@@ -1,7 +1,11 @@
 use crate::{
     commands::play::QueryType,
     errors::ParrotError,
-    messaging::messages::{SPOTIFY_INVALID_QUERY, SPOTIFY_PLAYLIST_FAILED},
+    messaging::messages::{
+        FAIL_SPOTIFY_ALBUM, FAIL_SPOTIFY_ALBUM_ID, FAIL_SPOTIFY_AUTH, FAIL_SPOTIFY_CLIENT_ID,
+        FAIL_SPOTIFY_CLIENT_SECRET, FAIL_SPOTIFY_PLAYLIST_ID, FAIL_SPOTIFY_TRACK,
+        FAIL_SPOTIFY_TRACK_ID, SPOTIFY_INVALID_QUERY, SPOTIFY_PLAYLIST_FAILED,
+    },
 };
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -15,7 +19,7 @@ use tokio::sync::Mutex;
 
 lazy_static! {
     pub static ref SPOTIFY: Mutex<Result<ClientCredsSpotify, ParrotError>> =
-        Mutex::new(Err(ParrotError::Other("no auth attempts")));
+        Mutex::new(Err(ParrotError::Other(FAIL_SPOTIFY_AUTH)));
     pub static ref SPOTIFY_QUERY_REGEX: Regex =
         Regex::new(r"spotify.com/(?P<media_type>.+)/(?P<media_id>.*?)(?:\?|$)").unwrap();
 }
@@ -45,10 +49,10 @@ pub struct Spotify {}
 impl Spotify {
     pub async fn auth() -> Result<ClientCredsSpotify, ParrotError> {
         let spotify_client_id = env::var("SPOTIFY_CLIENT_ID")
-            .map_err(|_| ParrotError::Other("missing spotify client ID"))?;
+            .map_err(|_| ParrotError::Other(FAIL_SPOTIFY_CLIENT_ID))?;
 
         let spotify_client_secret = env::var("SPOTIFY_CLIENT_SECRET")
-            .map_err(|_| ParrotError::Other("missing spotify client secret"))?;
+            .map_err(|_| ParrotError::Other(FAIL_SPOTIFY_CLIENT_SECRET))?;
 
         let creds = Credentials::new(&spotify_client_id, &spotify_client_secret);
 
@@ -90,13 +94,13 @@ impl Spotify {
         spotify: &ClientCredsSpotify,
         id: &str,
     ) -> Result<QueryType, ParrotError> {
-        let track_id = TrackId::from_id(id)
-            .map_err(|_| ParrotError::Other("track ID contains invalid characters"))?;
+        let track_id =
+            TrackId::from_id(id).map_err(|_| ParrotError::Other(FAIL_SPOTIFY_TRACK_ID))?;
 
         let track = spotify
             .track(track_id, None)
             .await
-            .map_err(|_| ParrotError::Other("failed to fetch track"))?;
+            .map_err(|_| ParrotError::Other(FAIL_SPOTIFY_TRACK))?;
 
         let artist_names = Self::join_artist_names(&track.artists);
 
@@ -108,13 +112,13 @@ impl Spotify {
         spotify: &ClientCredsSpotify,
         id: &str,
     ) -> Result<QueryType, ParrotError> {
-        let album_id = AlbumId::from_id(id)
-            .map_err(|_| ParrotError::Other("album ID contains invalid characters"))?;
+        let album_id =
+            AlbumId::from_id(id).map_err(|_| ParrotError::Other(FAIL_SPOTIFY_ALBUM_ID))?;
 
         let album = spotify
             .album(album_id, None)
             .await
-            .map_err(|_| ParrotError::Other("failed to fetch album"))?;
+            .map_err(|_| ParrotError::Other(FAIL_SPOTIFY_ALBUM))?;
 
         let artist_names = Self::join_artist_names(&album.artists);
 
@@ -132,8 +136,8 @@ impl Spotify {
         spotify: &ClientCredsSpotify,
         id: &str,
     ) -> Result<QueryType, ParrotError> {
-        let playlist_id = PlaylistId::from_id(id)
-            .map_err(|_| ParrotError::Other("playlist ID contains invalid characters"))?;
+        let playlist_id =
+            PlaylistId::from_id(id).map_err(|_| ParrotError::Other(FAIL_SPOTIFY_PLAYLIST_ID))?;
 
         let playlist = spotify
             .playlist(playlist_id, None, None)
@@ -1,9 +1,25 @@
-use crate::commands::play::{Mode, QueryType};
+use crate::{
+    commands::play::{Mode, QueryType},
+    errors::ParrotError,
+    guild::filter::FilterRule,
+    messaging::i18n::tr,
+    messaging::messages::{
+        UNAVAILABLE_AGE_RESTRICTED, UNAVAILABLE_DELETED, UNAVAILABLE_FILTERED,
+        UNAVAILABLE_FILTERED_SUFFIX, UNAVAILABLE_GEO_BLOCKED, UNAVAILABLE_NOT_FOUND,
+        UNAVAILABLE_OTHER, UNAVAILABLE_PRIVATE,
+    },
+    sources::cache::{save_metadata_cache, MetadataCache, METADATA_CACHE},
+};
 use serde_json::Value;
-use songbird::input::{AuxMetadata, Compose, Input, YoutubeDl};
+use songbird::{
+    constants::SAMPLE_RATE_RAW,
+    input::{AuxMetadata, Input, YoutubeDl},
+};
+use std::fmt::{self, Display};
 use std::io::BufRead;
 use std::process::Stdio;
 use std::sync::OnceLock;
+use std::time::Duration;
 use tokio::process::Command as TokioCommand;
 
 static HTTP_CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
@@ -26,39 +42,239 @@ impl YouTube {
     }
 }
 
+/// Why yt-dlp couldn't resolve a video, guessed from its error output.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UnavailableReason {
+    Private,
+    Deleted,
+    GeoBlocked,
+    AgeRestricted,
+    NotFound,
+    Filtered(FilterRule),
+    Other,
+}
+
+impl UnavailableReason {
+    pub fn from_error(err: &str) -> Self {
+        let err = err.to_lowercase();
+        let matches_any = |patterns: &[&str]| patterns.iter().any(|p| err.contains(p));
+
+        if matches_any(&["private video", "video is private"]) {
+            Self::Private
+        } else if matches_any(&["confirm your age", "age-restricted", "age restricted"]) {
+            Self::AgeRestricted
+        } else if matches_any(&["your country", "geo restrict", "geo-restrict"]) {
+            Self::GeoBlocked
+        } else if matches_any(&["removed", "deleted", "terminated", "video unavailable"]) {
+            Self::Deleted
+        } else if matches_any(&["no results found"]) {
+            Self::NotFound
+        } else {
+            Self::Other
+        }
+    }
+}
+
+impl Display for UnavailableReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Private => f.write_str(tr(UNAVAILABLE_PRIVATE)),
+            Self::Deleted => f.write_str(tr(UNAVAILABLE_DELETED)),
+            Self::GeoBlocked => f.write_str(tr(UNAVAILABLE_GEO_BLOCKED)),
+            Self::AgeRestricted => f.write_str(tr(UNAVAILABLE_AGE_RESTRICTED)),
+            Self::NotFound => f.write_str(tr(UNAVAILABLE_NOT_FOUND)),
+            Self::Filtered(rule) => f.write_str(&format!(
+                "{} {} {}",
+                tr(UNAVAILABLE_FILTERED),
+                rule,
+                tr(UNAVAILABLE_FILTERED_SUFFIX)
+            )),
+            Self::Other => f.write_str(tr(UNAVAILABLE_OTHER)),
+        }
+    }
+}
+
+/// A selection of playlist entries by their 1-based position, mapped onto yt-dlp's
+/// `--playlist-items` so that unwanted entries are never fetched.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PlaylistRange {
+    pub start: Option<usize>,
+    pub end: Option<usize>,
+    pub limit: Option<usize>,
+}
+
+impl PlaylistRange {
+    /// Returns the `--playlist-items` value, or `None` when the whole playlist is selected.
+    pub fn to_items_arg(&self) -> Option<String> {
+        if *self == Self::default() {
+            return None;
+        }
+
+        let start = self.start.unwrap_or(1).max(1);
+        let limit_end = self.limit.map(|limit| start + limit.max(1) - 1);
+
+        let end = match (self.end, limit_end) {
+            (Some(end), Some(limit_end)) => Some(end.min(limit_end)),
+            (end, limit_end) => end.or(limit_end),
+        };
+
+        match end {
+            Some(end) => Some(format!("{}:{}", start, end)),
+            None => Some(format!("{}:", start)),
+        }
+    }
+}
+
+/// A resolved track together with the age limit that songbird's metadata leaves out.
+pub struct ResolvedTrack {
+    pub source: Input,
+    pub metadata: AuxMetadata,
+    pub age_limit: Option<u64>,
+}
+
+impl ResolvedTrack {
+    fn new(url: String, metadata: AuxMetadata, age_limit: Option<u64>) -> Self {
+        Self {
+            source: YoutubeDl::new(get_http_client().clone(), url).into(),
+            metadata,
+            age_limit,
+        }
+    }
+}
+
 pub struct YouTubeRestartable {}
 
 impl YouTubeRestartable {
-    /// Creates a YouTube input and fetches its metadata
+    /// Creates a YouTube input and fetches its metadata, preferring the metadata cache
     pub async fn ytdl<P: AsRef<str> + Send + Clone + Sync + 'static>(
         uri: P,
-    ) -> Result<(Input, AuxMetadata), crate::errors::ParrotError> {
-        let mut source = YoutubeDl::new(get_http_client().clone(), uri.as_ref().to_string());
-        let metadata = source.aux_metadata().await.map_err(|e| {
-            crate::errors::ParrotError::TrackFail(format!("Failed to get metadata: {}", e))
-        })?;
-        Ok((source.into(), metadata))
+    ) -> Result<ResolvedTrack, ParrotError> {
+        let url = uri.as_ref().to_string();
+        let key = MetadataCache::url_key(&url);
+
+        if let Some((metadata, resolved_url, age_limit)) = METADATA_CACHE.lock().await.get(&key) {
+            return Ok(ResolvedTrack::new(resolved_url, metadata, age_limit));
+        }
+
+        let (metadata, age_limit) = Self::ytdl_metadata(&url).await?;
+
+        let resolved_url = metadata.source_url.clone().unwrap_or(url);
+        let keys = [key, MetadataCache::url_key(&resolved_url)];
+        METADATA_CACHE
+            .lock()
+            .await
+            .insert(&keys, &metadata, &resolved_url, age_limit);
+        tokio::spawn(save_metadata_cache(false));
+
+        Ok(ResolvedTrack::new(resolved_url, metadata, age_limit))
     }
 
-    /// Creates a YouTube search input and fetches its metadata
+    /// Creates a YouTube search input and fetches its metadata, preferring the metadata cache
     pub async fn ytdl_search<P: AsRef<str> + Send + Clone + Sync + 'static>(
         uri: P,
-    ) -> Result<(Input, AuxMetadata), crate::errors::ParrotError> {
-        let mut source = YoutubeDl::new_search(get_http_client().clone(), uri.as_ref().to_string());
-        let metadata = source.aux_metadata().await.map_err(|e| {
-            crate::errors::ParrotError::TrackFail(format!("Failed to get metadata: {}", e))
-        })?;
-        Ok((source.into(), metadata))
+    ) -> Result<ResolvedTrack, ParrotError> {
+        let query = uri.as_ref().to_string();
+        let key = MetadataCache::query_key(&query);
+
+        if let Some((metadata, resolved_url, age_limit)) = METADATA_CACHE.lock().await.get(&key) {
+            return Ok(ResolvedTrack::new(resolved_url, metadata, age_limit));
+        }
+
+        let (metadata, age_limit) = Self::ytdl_metadata(&format!("ytsearch1:{}", query)).await?;
+
+        // a search can only be cached once we know which video it resolved to
+        let Some(resolved_url) = metadata.source_url.clone() else {
+            let source = YoutubeDl::new_search(get_http_client().clone(), query).into();
+            return Ok(ResolvedTrack {
+                source,
+                metadata,
+                age_limit,
+            });
+        };
+
+        let keys = [key, MetadataCache::url_key(&resolved_url)];
+        METADATA_CACHE
+            .lock()
+            .await
+            .insert(&keys, &metadata, &resolved_url, age_limit);
+        tokio::spawn(save_metadata_cache(false));
+
+        Ok(ResolvedTrack::new(resolved_url, metadata, age_limit))
+    }
+
+    /// Fetches a track's metadata the same way songbird does, but in a single call that
+    /// also reads its age limit, so that filtering doesn't need a second extraction.
+    async fn ytdl_metadata(query: &str) -> Result<(AuxMetadata, Option<u64>), ParrotError> {
+        let output = TokioCommand::new("yt-dlp")
+            .args([
+                "-j",
+                query,
+                "-f",
+                "ba[abr>0][vcodec=none]/best",
+                "--no-playlist",
+            ])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .await
+            .map_err(|e| ParrotError::TrackFail(format!("Failed to get metadata: {}", e)))?;
+
+        if !output.status.success() {
+            return Err(ParrotError::TrackFail(format!(
+                "Failed to get metadata: yt-dlp failed with non-zero status code: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )));
+        }
+
+        let info: Value = output
+            .stdout
+            .lines()
+            .map_while(Result::ok)
+            .find(|line| !line.trim().is_empty())
+            .and_then(|line| serde_json::from_str(&line).ok())
+            .ok_or_else(|| {
+                ParrotError::TrackFail("Failed to get metadata: no results found".to_string())
+            })?;
+
+        let age_limit = info.get("age_limit").and_then(Value::as_u64);
+        Ok((Self::metadata_from_info(&info), age_limit))
     }
 
-    pub async fn ytdl_playlist(uri: &str, mode: Mode) -> Option<Vec<String>> {
-        let mut args = vec![uri, "--flat-playlist", "-j"];
+    /// Mirrors how songbird maps yt-dlp's output onto [`AuxMetadata`].
+    fn metadata_from_info(info: &Value) -> AuxMetadata {
+        let text = |key: &str| info.get(key).and_then(Value::as_str).map(str::to_string);
+
+        AuxMetadata {
+            track: text("track"),
+            artist: text("artist").or_else(|| text("uploader")),
+            album: text("album"),
+            date: text("release_date").or_else(|| text("upload_date")),
+            channels: Some(2),
+            channel: text("channel"),
+            duration: info
+                .get("duration")
+                .and_then(Value::as_f64)
+                .map(Duration::from_secs_f64),
+            sample_rate: Some(SAMPLE_RATE_RAW as u32),
+            source_url: text("webpage_url"),
+            title: text("title"),
+            thumbnail: text("thumbnail"),
+            ..Default::default()
+        }
+    }
+
+    pub async fn ytdl_playlist(uri: &str, mode: Mode, range: PlaylistRange) -> Option<Vec<String>> {
+        let mut args = vec![uri.to_string(), "--flat-playlist".into(), "-j".into()];
         match mode {
-            Mode::Reverse => args.push("--playlist-reverse"),
-            Mode::Shuffle => args.push("--playlist-random"),
+            Mode::Reverse => args.push("--playlist-reverse".into()),
+            Mode::Shuffle => args.push("--playlist-random".into()),
             _ => {}
         }
 
+        if let Some(items) = range.to_items_arg() {
+            args.extend(["--playlist-items".into(), items]);
+        }
+
         let output = TokioCommand::new("yt-dlp")
             .args(args)
             .stdout(Stdio::piped())
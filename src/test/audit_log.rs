@@ -0,0 +1,31 @@
+use std::collections::HashSet;
+
+use crate::handlers::audit_log::{describe_tracks, diff_values};
+
+#[test]
+fn test_describe_tracks() {
+    let titles: Vec<String> = (1..=12).map(|i| format!("Track {}", i)).collect();
+
+    assert_eq!(describe_tracks(&titles[..2]), "`1.` Track 1\n`2.` Track 2");
+
+    let text = describe_tracks(&titles);
+    assert!(text.contains("`10.` Track 10"));
+    assert!(!text.contains("Track 11"));
+    assert!(text.ends_with("… 2 more"));
+}
+
+#[test]
+fn test_diff_values() {
+    let set = |values: &[&str]| -> HashSet<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    };
+
+    let before = set(&["youtube.com", "youtu.be"]);
+    assert_eq!(diff_values("Allowed", &before, &before), None);
+
+    let after = set(&["youtube.com", "soundcloud.com"]);
+    assert_eq!(
+        diff_values("Allowed", &before, &after),
+        Some("**Allowed**: +`soundcloud.com`, -`youtu.be`".to_string())
+    );
+}
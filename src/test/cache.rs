@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+use songbird::input::AuxMetadata;
+
+use crate::sources::cache::MetadataCache;
+
+fn metadata(title: &str) -> AuxMetadata {
+    AuxMetadata {
+        title: Some(title.to_string()),
+        duration: Some(Duration::from_secs(212)),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_query_key_normalization() {
+    assert_eq!(
+        MetadataCache::query_key("  Rick   Astley -  Never Gonna Give You Up "),
+        MetadataCache::query_key("rick astley - never gonna give you up")
+    );
+    assert_ne!(
+        MetadataCache::query_key("never gonna"),
+        MetadataCache::url_key("never gonna")
+    );
+}
+
+#[test]
+fn test_cache_hits_and_misses() {
+    let mut cache = MetadataCache::new(None, Duration::from_secs(3600), 10);
+    let key = MetadataCache::query_key("parrot");
+
+    assert_eq!(cache.get(&key), None);
+    cache.insert(
+        std::slice::from_ref(&key),
+        &metadata("Parrot"),
+        "https://youtu.be/parrot",
+        Some(18),
+    );
+
+    let (cached, url, age_limit) = cache.get(&key).unwrap();
+    assert_eq!(cached, metadata("Parrot"));
+    assert_eq!(url, "https://youtu.be/parrot");
+    assert_eq!(age_limit, Some(18));
+
+    let stats = cache.stats();
+    assert_eq!((stats.entries, stats.hits, stats.misses), (1, 1, 1));
+}
+
+#[test]
+fn test_cache_expiry_and_capacity() {
+    let mut cache = MetadataCache::new(None, Duration::ZERO, 10);
+    let key = MetadataCache::url_key("https://youtu.be/parrot");
+    cache.insert(
+        std::slice::from_ref(&key),
+        &metadata("Parrot"),
+        "https://youtu.be/parrot",
+        None,
+    );
+    assert_eq!(cache.get(&key), None);
+
+    let mut cache = MetadataCache::new(None, Duration::from_secs(3600), 2);
+    let keys: Vec<String> = (0..5)
+        .map(|i| MetadataCache::url_key(&i.to_string()))
+        .collect();
+    for key in keys.iter() {
+        cache.insert(std::slice::from_ref(key), &metadata(key), key, None);
+    }
+    assert_eq!(cache.stats().entries, 2);
+}
@@ -0,0 +1,31 @@
+use crate::client::ShardConfig;
+
+#[test]
+fn test_shard_config_parsing() {
+    assert_eq!(ShardConfig::parse(None, None), Ok(ShardConfig::Single));
+    assert_eq!(
+        ShardConfig::parse(Some("auto"), None),
+        Ok(ShardConfig::Auto)
+    );
+    assert_eq!(
+        ShardConfig::parse(Some("4"), None),
+        Ok(ShardConfig::Total(4))
+    );
+    assert_eq!(
+        ShardConfig::parse(Some("8"), Some("2-5")),
+        Ok(ShardConfig::Range {
+            first: 2,
+            last: 5,
+            total: 8
+        })
+    );
+}
+
+#[test]
+fn test_invalid_shard_config() {
+    assert!(ShardConfig::parse(Some("0"), None).is_err());
+    assert!(ShardConfig::parse(Some("many"), None).is_err());
+    assert!(ShardConfig::parse(Some("auto"), Some("0-1")).is_err());
+    assert!(ShardConfig::parse(Some("4"), Some("3-1")).is_err());
+    assert!(ShardConfig::parse(Some("4"), Some("2-4")).is_err());
+}
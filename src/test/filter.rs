@@ -0,0 +1,71 @@
+use crate::guild::filter::{ContentFilter, FilterRule};
+use songbird::input::AuxMetadata;
+use std::time::Duration;
+
+fn metadata(title: &str, channel: &str, duration: Option<u64>) -> AuxMetadata {
+    AuxMetadata {
+        title: Some(title.to_string()),
+        channel: Some(channel.to_string()),
+        duration: duration.map(Duration::from_secs),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_filter_keywords() {
+    let mut filter = ContentFilter::default();
+    filter
+        .set_title_pattern(Some(r"\b(nightcore|sped up)\b".to_string()))
+        .unwrap();
+    filter
+        .set_channel_pattern(Some("^spam".to_string()))
+        .unwrap();
+
+    let track = metadata("Song (Nightcore)", "Artist", Some(180));
+    assert_eq!(filter.check(&track, None), Err(FilterRule::Title));
+
+    let track = metadata("Song", "Spammy Uploads", Some(180));
+    assert_eq!(filter.check(&track, None), Err(FilterRule::Channel));
+
+    let track = metadata("Song", "Artist", Some(180));
+    assert_eq!(filter.check(&track, None), Ok(()));
+
+    assert!(filter.set_title_pattern(Some("(".to_string())).is_err());
+    assert!(filter.title_pattern.is_some());
+}
+
+#[test]
+fn test_filter_livestreams_and_duration() {
+    let mut filter = ContentFilter::default();
+    assert_eq!(
+        filter.check(&metadata("Live", "Artist", None), None),
+        Ok(())
+    );
+
+    filter.block_livestreams = true;
+    filter.max_duration = Some(600);
+
+    let live = metadata("Live", "Artist", None);
+    assert_eq!(filter.check(&live, None), Err(FilterRule::Livestream));
+
+    let long = metadata("Mix", "Artist", Some(3600));
+    assert_eq!(filter.check(&long, None), Err(FilterRule::MaxDuration(600)));
+
+    let short = metadata("Song", "Artist", Some(600));
+    assert_eq!(filter.check(&short, None), Ok(()));
+}
+
+#[test]
+fn test_filter_age_restricted() {
+    let mut filter = ContentFilter::default();
+    let track = metadata("Song", "Artist", Some(180));
+    assert_eq!(filter.check(&track, Some(18)), Ok(()));
+
+    filter.block_age_restricted = true;
+    assert_eq!(
+        filter.check(&track, Some(18)),
+        Err(FilterRule::AgeRestricted)
+    );
+    assert_eq!(filter.check(&track, Some(0)), Ok(()));
+    assert_eq!(filter.check(&track, None), Ok(()));
+}
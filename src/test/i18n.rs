@@ -0,0 +1,78 @@
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+
+use crate::messaging::{
+    i18n::{resolve_locale, tr, tr_in, with_locale},
+    messages::{FAIL_NOT_DJ, QUEUE_IS_EMPTY},
+};
+
+#[test]
+fn test_resolve_locale() {
+    assert_eq!(resolve_locale("pt-BR"), Some("pt-BR"));
+    assert_eq!(resolve_locale("es-419"), Some("es-ES"));
+    assert_eq!(resolve_locale("pt-PT"), Some("pt-BR"));
+    assert_eq!(resolve_locale("en-US"), None);
+    assert_eq!(resolve_locale("de"), None);
+}
+
+#[test]
+fn test_tr_in() {
+    assert_ne!(tr_in(Some("pt-BR"), FAIL_NOT_DJ), FAIL_NOT_DJ);
+    assert_ne!(tr_in(Some("es-ES"), FAIL_NOT_DJ), FAIL_NOT_DJ);
+    assert_eq!(tr_in(None, FAIL_NOT_DJ), FAIL_NOT_DJ);
+    assert_eq!(tr_in(Some("de"), FAIL_NOT_DJ), FAIL_NOT_DJ);
+}
+
+#[tokio::test]
+async fn test_with_locale() {
+    let translated = with_locale(Some("es-419"), async { tr(QUEUE_IS_EMPTY) }).await;
+    assert_eq!(translated, tr_in(Some("es-ES"), QUEUE_IS_EMPTY));
+    assert_eq!(tr(QUEUE_IS_EMPTY), QUEUE_IS_EMPTY);
+}
+
+#[test]
+fn test_catalogs_match() {
+    let catalogs = [
+        include_str!("../../locales/es-ES.json"),
+        include_str!("../../locales/pt-BR.json"),
+    ]
+    .map(|source| {
+        serde_json::from_str::<HashMap<String, String>>(source)
+            .unwrap()
+            .into_keys()
+            .collect::<HashSet<_>>()
+    });
+
+    assert_eq!(catalogs[0], catalogs[1]);
+}
+
+#[test]
+fn test_catalog_keys_are_used() {
+    // every English text that can be translated is either a message or a command description
+    let sources = [
+        include_str!("../messaging/messages.rs"),
+        include_str!("../handlers/serenity.rs"),
+    ];
+    let literal = Regex::new(r#""((?:[^"\\]|\\.)*)""#).unwrap();
+    let escape = Regex::new(r"\\(.)").unwrap();
+    let texts: HashSet<String> = sources
+        .iter()
+        .flat_map(|source| literal.captures_iter(source))
+        .map(|captures| {
+            escape
+                .replace_all(&captures[1], |escaped: &regex::Captures| {
+                    match &escaped[1] {
+                        "n" => "\n".to_string(),
+                        other => other.to_string(),
+                    }
+                })
+                .into_owned()
+        })
+        .collect();
+
+    let catalog: HashMap<String, String> =
+        serde_json::from_str(include_str!("../../locales/es-ES.json")).unwrap();
+    let unused: Vec<_> = catalog.keys().filter(|key| !texts.contains(*key)).collect();
+
+    assert!(unused.is_empty(), "unused catalog keys: {:?}", unused);
+}
@@ -1,2 +1,11 @@
+pub mod audit_log;
+pub mod cache;
+pub mod client;
 pub mod errors;
+pub mod filter;
+pub mod i18n;
+pub mod rate_limit;
+pub mod reconnect;
+pub mod settings;
 pub mod utils;
+pub mod youtube;
@@ -0,0 +1,63 @@
+use std::time::{Duration, Instant};
+
+use serenity::model::id::UserId;
+
+use crate::guild::rate_limit::{QueueRateLimit, RateLimiter};
+
+#[test]
+fn test_cooldown_left() {
+    let mut rate_limiter = RateLimiter::default();
+    let user_id = UserId::new(1);
+    let cooldown = Duration::from_secs(10);
+    let start = Instant::now();
+
+    assert_eq!(
+        rate_limiter.cooldown_left(user_id, "play", cooldown, start),
+        None
+    );
+    rate_limiter.record(user_id, "play", start);
+
+    let later = start + Duration::from_secs(4);
+    assert_eq!(
+        rate_limiter.cooldown_left(user_id, "play", cooldown, later),
+        Some(Duration::from_secs(6))
+    );
+    assert_eq!(
+        rate_limiter.cooldown_left(user_id, "skip", cooldown, later),
+        None
+    );
+    assert_eq!(
+        rate_limiter.cooldown_left(UserId::new(2), "play", cooldown, later),
+        None
+    );
+
+    let done = start + cooldown;
+    assert_eq!(
+        rate_limiter.cooldown_left(user_id, "play", cooldown, done),
+        None
+    );
+}
+
+#[test]
+fn test_rate_limit_left() {
+    let mut rate_limiter = RateLimiter::default();
+    let limit = QueueRateLimit {
+        commands: 2,
+        window: 10,
+    };
+    let start = Instant::now();
+
+    rate_limiter.record(UserId::new(1), "play", start);
+    rate_limiter.record(UserId::new(2), "version", start);
+    assert_eq!(rate_limiter.rate_limit_left(limit, start), None);
+
+    rate_limiter.record(UserId::new(2), "skip", start + Duration::from_secs(3));
+    assert_eq!(
+        rate_limiter.rate_limit_left(limit, start + Duration::from_secs(5)),
+        Some(Duration::from_secs(5))
+    );
+    assert_eq!(
+        rate_limiter.rate_limit_left(limit, start + Duration::from_secs(10)),
+        None
+    );
+}
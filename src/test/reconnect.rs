@@ -0,0 +1,42 @@
+use crate::handlers::reconnect::is_transient_disconnect;
+use songbird::{
+    events::context_data::{DisconnectKind, DisconnectReason},
+    model::CloseCode,
+};
+
+#[test]
+fn test_transient_disconnects() {
+    let transient = [
+        None,
+        Some(DisconnectReason::Io),
+        Some(DisconnectReason::TimedOut),
+        Some(DisconnectReason::WsClosed(None)),
+        Some(DisconnectReason::WsClosed(Some(
+            CloseCode::VoiceServerCrash,
+        ))),
+    ];
+
+    for reason in transient {
+        assert!(is_transient_disconnect(DisconnectKind::Runtime, reason));
+        assert!(is_transient_disconnect(DisconnectKind::Reconnect, reason));
+    }
+}
+
+#[test]
+fn test_deliberate_disconnects() {
+    let deliberate = [
+        Some(DisconnectReason::Requested),
+        Some(DisconnectReason::AttemptDiscarded),
+        Some(DisconnectReason::WsClosed(Some(CloseCode::Disconnected))),
+    ];
+
+    for reason in deliberate {
+        assert!(!is_transient_disconnect(DisconnectKind::Runtime, reason));
+    }
+
+    // failing to connect in the first place is reported back to whoever summoned the bot
+    assert!(!is_transient_disconnect(
+        DisconnectKind::Connect,
+        Some(DisconnectReason::TimedOut)
+    ));
+}
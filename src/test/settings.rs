@@ -0,0 +1,96 @@
+use crate::{
+    commands::voteskip::required_votes,
+    guild::settings::{BanScope, CommandPolicy, GuildSettings, MusicBan},
+};
+use serenity::model::id::{ChannelId, GuildId, UserId};
+
+#[test]
+fn test_command_policy_allows() {
+    assert!(CommandPolicy::Everyone.allows(false, false));
+
+    assert!(!CommandPolicy::Dj.allows(false, false));
+    assert!(CommandPolicy::Dj.allows(true, false));
+    assert!(CommandPolicy::Dj.allows(false, true));
+
+    assert!(!CommandPolicy::Admin.allows(true, false));
+    assert!(CommandPolicy::Admin.allows(false, true));
+
+    assert!(!CommandPolicy::Disabled.allows(true, true));
+}
+
+#[test]
+fn test_command_policy_ids() {
+    for policy in CommandPolicy::ALL {
+        assert_eq!(CommandPolicy::from_id(policy.id()), Some(policy));
+    }
+    assert_eq!(CommandPolicy::from_id("default"), None);
+}
+
+#[test]
+fn test_required_skip_votes() {
+    assert_eq!(required_votes(0, 50), 1);
+    assert_eq!(required_votes(1, 50), 1);
+    assert_eq!(required_votes(3, 50), 2);
+    assert_eq!(required_votes(4, 50), 2);
+    assert_eq!(required_votes(4, 100), 4);
+    assert_eq!(required_votes(10, 1), 1);
+}
+
+#[test]
+fn test_set_vote_percent() {
+    let mut settings = GuildSettings::new(GuildId::new(1));
+
+    settings.set_vote_percent("skip", 75);
+    assert_eq!(settings.skip_vote_percent, 75);
+
+    assert_eq!(settings.set_vote_percent("stop", 0), 1);
+    assert_eq!(settings.vote_percents.get("stop"), Some(&1));
+}
+
+#[test]
+fn test_allowed_channels() {
+    let mut settings = GuildSettings::new(GuildId::new(1));
+    let (general, music) = (ChannelId::new(2), ChannelId::new(3));
+
+    assert!(settings.is_text_channel_allowed(general));
+
+    assert!(settings.toggle_text_channel(music));
+    assert!(settings.is_text_channel_allowed(music));
+    assert!(!settings.is_text_channel_allowed(general));
+
+    assert!(!settings.toggle_text_channel(music));
+    assert!(settings.is_text_channel_allowed(general));
+    assert!(settings.is_voice_channel_allowed(general));
+}
+
+#[test]
+fn test_music_bans() {
+    let mut settings = GuildSettings::new(GuildId::new(1));
+    let user_id = UserId::new(2);
+
+    let ban = MusicBan {
+        scope: BanScope::Queue,
+        expires: Some(100),
+    };
+    settings.ban_user(user_id, ban, 0);
+
+    assert_eq!(settings.get_ban(user_id, 99), Some(ban));
+    assert_eq!(settings.get_ban(user_id, 100), None);
+    assert!(ban.applies_to("play"));
+    assert!(!ban.applies_to("skip"));
+
+    let json = serde_json::to_string(&settings).unwrap();
+    let restored: GuildSettings = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.music_bans.get(&user_id), Some(&ban));
+
+    assert!(!settings.unban_user(user_id, 100));
+    assert!(!settings.unban_user(user_id, 0));
+
+    let ban = MusicBan {
+        scope: BanScope::All,
+        expires: None,
+    };
+    settings.ban_user(user_id, ban, 0);
+    assert!(ban.applies_to("skip"));
+    assert!(settings.unban_user(user_id, i64::MAX));
+}
@@ -1,6 +1,14 @@
 use std::time::Duration;
 
-use crate::utils::get_human_readable_timestamp;
+use url::Url;
+
+use crate::{
+    sources::links::{is_public_ip, normalize_url},
+    utils::{
+        compare_domains, create_progress_bar, get_human_readable_timestamp, normalize_domain,
+        parse_domains, Debouncer,
+    },
+};
 
 #[test]
 fn test_get_human_readable_timestamp() {
@@ -22,3 +30,111 @@ fn test_get_human_readable_timestamp() {
     let result = get_human_readable_timestamp(None);
     assert_eq!(result, "∞");
 }
+
+#[test]
+fn test_debouncer() {
+    let mut debouncer = Debouncer::new(Duration::from_secs(3600));
+    assert!(debouncer.ready());
+    assert!(!debouncer.ready());
+
+    let mut debouncer = Debouncer::new(Duration::ZERO);
+    assert!(debouncer.ready());
+    assert!(debouncer.ready());
+}
+
+#[test]
+fn test_create_progress_bar() {
+    assert_eq!(create_progress_bar(0, 10), "░".repeat(20));
+    assert_eq!(create_progress_bar(5, 10), "█".repeat(10) + &"░".repeat(10));
+    assert_eq!(create_progress_bar(10, 10), "█".repeat(20));
+    assert_eq!(create_progress_bar(3, 0), "█".repeat(20));
+}
+
+#[test]
+fn test_compare_domains() {
+    assert!(compare_domains("youtube.com", "youtube.com"));
+    assert!(compare_domains("youtube.com", "music.youtube.com"));
+    assert!(compare_domains("YouTube.com", "www.youtube.com."));
+    assert!(!compare_domains("youtube.com", "evilyoutube.com"));
+    assert!(!compare_domains("youtube.com", "youtube.com.evil.net"));
+
+    assert!(compare_domains("*.youtube.com", "m.youtube.com"));
+    assert!(!compare_domains("*.youtube.com", "youtube.com"));
+    assert!(compare_domains("youtube.*", "www.youtube.de"));
+    assert!(!compare_domains("youtube.*", "youtube.co.uk"));
+}
+
+#[test]
+fn test_normalize_domain() {
+    assert_eq!(
+        normalize_domain(" YouTube.com "),
+        Some("youtube.com".to_string())
+    );
+    assert_eq!(
+        normalize_domain("www.youtube.com"),
+        Some("youtube.com".to_string())
+    );
+    assert_eq!(
+        normalize_domain("https://music.youtube.com/watch?v=id"),
+        Some("music.youtube.com".to_string())
+    );
+    assert_eq!(
+        normalize_domain("*.example.com"),
+        Some("*.example.com".to_string())
+    );
+    assert_eq!(normalize_domain("localhost"), None);
+    assert_eq!(normalize_domain("exa mple.com"), None);
+    assert_eq!(normalize_domain("-bad.com"), None);
+    assert_eq!(normalize_domain("bad..com"), None);
+}
+
+#[test]
+fn test_parse_domains() {
+    let domains = parse_domains("youtube.com; youtu.be,\nsoundcloud.com;").unwrap();
+    assert_eq!(domains.len(), 3);
+    assert!(domains.contains("youtu.be"));
+
+    let invalid = parse_domains("youtube.com;not a domain;bad_host.com").unwrap_err();
+    assert_eq!(invalid, vec!["not", "a", "domain", "bad_host.com"]);
+}
+
+#[test]
+fn test_normalize_url() {
+    let normalize = |url: &str| normalize_url(Url::parse(url).unwrap()).to_string();
+
+    assert_eq!(
+        normalize("https://music.youtube.com/watch?v=id"),
+        "https://www.youtube.com/watch?v=id"
+    );
+    assert_eq!(
+        normalize("https://youtu.be/id?t=42"),
+        "https://www.youtube.com/watch?v=id&t=42"
+    );
+    assert_eq!(
+        normalize("https://open.spotify.com/intl-de/track/id?si=x"),
+        "https://open.spotify.com/track/id?si=x"
+    );
+    assert_eq!(
+        normalize("https://soundcloud.com/artist/track"),
+        "https://soundcloud.com/artist/track"
+    );
+}
+
+#[test]
+fn test_is_public_ip() {
+    let public = |ip: &str| is_public_ip(ip.parse().unwrap());
+
+    assert!(public("8.8.8.8"));
+    assert!(public("2606:4700:4700::1111"));
+
+    assert!(!public("127.0.0.1"));
+    assert!(!public("10.0.0.1"));
+    assert!(!public("192.168.1.1"));
+    assert!(!public("169.254.169.254"));
+    assert!(!public("100.64.0.1"));
+    assert!(!public("0.0.0.0"));
+    assert!(!public("::1"));
+    assert!(!public("fd00::1"));
+    assert!(!public("fe80::1"));
+    assert!(!public("::ffff:127.0.0.1"));
+}
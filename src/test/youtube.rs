@@ -0,0 +1,55 @@
+use crate::sources::youtube::{PlaylistRange, UnavailableReason};
+
+#[test]
+fn test_playlist_range_items() {
+    let range = PlaylistRange::default();
+    assert_eq!(range.to_items_arg(), None);
+
+    let range = PlaylistRange {
+        start: Some(20),
+        end: Some(40),
+        limit: None,
+    };
+    assert_eq!(range.to_items_arg(), Some("20:40".to_string()));
+
+    let range = PlaylistRange {
+        start: Some(20),
+        end: None,
+        limit: None,
+    };
+    assert_eq!(range.to_items_arg(), Some("20:".to_string()));
+
+    let range = PlaylistRange {
+        start: None,
+        end: None,
+        limit: Some(10),
+    };
+    assert_eq!(range.to_items_arg(), Some("1:10".to_string()));
+
+    let range = PlaylistRange {
+        start: Some(20),
+        end: Some(40),
+        limit: Some(5),
+    };
+    assert_eq!(range.to_items_arg(), Some("20:24".to_string()));
+}
+
+#[test]
+fn test_unavailable_reason_from_error() {
+    let reason = UnavailableReason::from_error("ERROR: [youtube] abc: Private video. Sign in");
+    assert_eq!(reason, UnavailableReason::Private);
+
+    let reason = UnavailableReason::from_error("Sign in to confirm your age");
+    assert_eq!(reason, UnavailableReason::AgeRestricted);
+
+    let reason = UnavailableReason::from_error(
+        "The uploader has not made this video available in your country",
+    );
+    assert_eq!(reason, UnavailableReason::GeoBlocked);
+
+    let reason = UnavailableReason::from_error("ERROR: [youtube] abc: Video unavailable");
+    assert_eq!(reason, UnavailableReason::Deleted);
+
+    let reason = UnavailableReason::from_error("something else entirely");
+    assert_eq!(reason, UnavailableReason::Other);
+}
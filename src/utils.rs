@@ -5,11 +5,16 @@ use serenity::{
     },
     builder::CreateEmbed,
     http::{Http, HttpError},
-    model::channel::Message,
+    model::{channel::Message, id::ChannelId, mention::Mention},
+    prelude::Mentionable,
     Error,
 };
 use songbird::tracks::TrackHandle;
-use std::{sync::Arc, time::Duration};
+use std::{
+    collections::HashSet,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 use url::Url;
 
 use crate::{
@@ -164,6 +169,129 @@ pub fn get_human_readable_timestamp(duration: Option<Duration>) -> String {
     }
 }
 
-pub fn compare_domains(domain: &str, subdomain: &str) -> bool {
-    subdomain == domain || subdomain.ends_with(domain)
+pub fn create_progress_bar(current: usize, total: usize) -> String {
+    const WIDTH: usize = 20;
+
+    let filled = match total {
+        0 => WIDTH,
+        _ => (current.min(total) * WIDTH) / total,
+    };
+
+    format!("{}{}", "█".repeat(filled), "░".repeat(WIDTH - filled))
+}
+
+/// Checks whether a host falls under a domain pattern, comparing whole labels only.
+/// A plain pattern covers the domain and all of its subdomains, so `youtube.com` matches
+/// `music.youtube.com` but not `evilyoutube.com`. A `*` label matches exactly one label,
+/// except in leading position where `*.example.com` matches any subdomain but not the apex.
+pub fn compare_domains(pattern: &str, host: &str) -> bool {
+    let pattern = pattern.trim_end_matches('.').to_lowercase();
+    let host = host.trim_end_matches('.').to_lowercase();
+
+    let pattern: Vec<&str> = pattern.split('.').collect();
+    let host: Vec<&str> = host.split('.').collect();
+
+    let (pattern, min_extra) = match pattern.split_first() {
+        Some((&"*", rest)) => (rest, 1),
+        _ => (pattern.as_slice(), 0),
+    };
+
+    if host.len() < pattern.len() + min_extra {
+        return false;
+    }
+
+    host[host.len() - pattern.len()..]
+        .iter()
+        .zip(pattern)
+        .all(|(label, expected)| *expected == "*" || label == expected)
+}
+
+/// Turns a domain typed by a user into the form stored in the settings, accepting
+/// full URLs as well. Returns `None` if it isn't a valid domain pattern.
+pub fn normalize_domain(input: &str) -> Option<String> {
+    let input = input.trim().to_lowercase();
+    let host = match Url::parse(&input) {
+        Ok(url) if url.has_host() => url.host_str()?.to_string(),
+        _ => input.split('/').next()?.to_string(),
+    };
+
+    let host = host.trim_end_matches('.');
+    let host = host.strip_prefix("www.").unwrap_or(host);
+
+    let is_valid_label = |label: &str| {
+        !label.is_empty()
+            && label.len() <= 63
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    };
+
+    let labels: Vec<&str> = host.split('.').collect();
+    let (first, rest) = labels.split_first()?;
+    let is_valid = host.len() <= 253
+        && !rest.is_empty()
+        && (*first == "*" || is_valid_label(first))
+        && rest
+            .iter()
+            .all(|label| *label == "*" || is_valid_label(label));
+
+    is_valid.then(|| host.to_string())
+}
+
+/// Splits a list of domains separated by semicolons, commas or whitespace,
+/// returning the entries that aren't valid domains as the error.
+pub fn parse_domains(input: &str) -> Result<HashSet<String>, Vec<String>> {
+    let mut domains = HashSet::new();
+    let mut invalid = Vec::new();
+
+    for entry in input
+        .split(|c: char| c == ';' || c == ',' || c.is_whitespace())
+        .filter(|entry| !entry.is_empty())
+    {
+        match normalize_domain(entry) {
+            Some(domain) => {
+                domains.insert(domain);
+            }
+            None => invalid.push(entry.to_string()),
+        }
+    }
+
+    match invalid.is_empty() {
+        true => Ok(domains),
+        false => Err(invalid),
+    }
+}
+
+/// Rate limits a recurring action, such as editing a message, to at most once per interval.
+/// The first call is always let through.
+pub struct Debouncer {
+    interval: Duration,
+    last: Option<Instant>,
+}
+
+impl Debouncer {
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last: None,
+        }
+    }
+
+    /// Returns whether the action may run now, recording it as run if so.
+    pub fn ready(&mut self) -> bool {
+        match self.last {
+            Some(last) if last.elapsed() < self.interval => false,
+            _ => {
+                self.last = Some(Instant::now());
+                true
+            }
+        }
+    }
+}
+
+/// Mentions every channel in a set, in a stable order.
+pub fn mention_all(channels: &HashSet<ChannelId>) -> Vec<Mention> {
+    let mut channels: Vec<_> = channels.iter().collect();
+    channels.sort();
+    channels.iter().map(|channel| channel.mention()).collect()
 }